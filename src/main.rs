@@ -1,42 +1,880 @@
 use iced::executor;
+use iced::multi_window::Application;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
 use iced::widget::{
-    column, container, row, text, Button, Radio, Scrollable, Space, Container,
+    checkbox, column, container, mouse_area, progress_bar, responsive, row, scrollable, slider, text,
+    text_input, tooltip, Button,
+    Radio, Scrollable, Space, Container,
 };
+use iced::theme::Palette;
 use iced::{
-    alignment, Alignment, Application, Border, Color, Command, Element, Length, 
-    Renderer,
-    Settings, Subscription, Theme,
+    alignment, event, keyboard, mouse, window, Alignment, Border, Color, Command, Element, Event,
+    Length, Point, Rectangle, Renderer,
+    Settings, Size, Subscription, Theme,
+};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use sysinfo::{
+    Components, Disks, Networks, Pid, ProcessRefreshKind, ProcessStatus, Signal, System, UpdateKind, Users,
 };
-use std::time::Duration;
-use sysinfo::{Pid, System};
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use directories::ProjectDirs;
+use num_format::{CustomFormat, Grouping, ToFormattedString};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum ThemeChoice {
     Light,
     Dark,
+    /// Follows the OS light/dark preference.
+    System,
 }
 
 impl ThemeChoice {
-    fn to_theme(&self) -> Theme {
+    /// Light or Dark, with `System` resolved from the last OS preference read.
+    fn resolve(self, system_dark: bool) -> ThemeChoice {
         match self {
+            ThemeChoice::System if system_dark => ThemeChoice::Dark,
+            ThemeChoice::System => ThemeChoice::Light,
+            choice => choice,
+        }
+    }
+
+    /// The built-in theme, or a copy of its palette with `accent` as the primary colour
+    /// (used by primary buttons and selected rows). `System` must be resolved first.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_theme(&self, accent: Option<[f32; 3]>) -> Theme {
+        let base = match self {
             ThemeChoice::Light => Theme::Light,
-            ThemeChoice::Dark => Theme::Dark,
+            ThemeChoice::Dark | ThemeChoice::System => Theme::Dark,
+        };
+        match accent {
+            Some([r, g, b]) => Theme::custom(
+                format!("{} (custom accent)", base),
+                Palette { primary: Color::from_rgb(r, g, b), ..base.palette() },
+            ),
+            None => base,
+        }
+    }
+}
+
+/// Display language for the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Language {
+    English,
+    Thai,
+}
+
+impl Language {
+    const ALL: [Language; 2] = [Language::English, Language::Thai];
+
+    /// Each language's own name, so it can be found whatever the current language is.
+    fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Thai => "ไทย",
+        }
+    }
+
+    /// Language for a POSIX locale string such as `th_TH.UTF-8`; English when unsupported.
+    fn from_locale(locale: &str) -> Self {
+        if locale.to_lowercase().starts_with("th") {
+            Language::Thai
+        } else {
+            Language::English
+        }
+    }
+
+    /// Language from the usual locale environment variables, in POSIX precedence order.
+    fn detect() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map_or(Language::English, |locale| Language::from_locale(&locale))
+    }
+}
+
+/// Translatable UI labels. Labels without an entry here are shown in English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UiText {
+    LoadingSettings,
+    TabDashboard,
+    TabProcesses,
+    TabNetwork,
+    TabDisks,
+    TabSettings,
+    Screenshot,
+    GoToPid,
+    Refresh,
+    Pause,
+    Resume,
+    Paused,
+    AppTitle,
+    RealTime,
+    CpuUsage,
+    Memory,
+    Swap,
+    CpuFrequency,
+    Cores,
+    Load,
+    Uptime,
+    NoSwapUsers,
+    NoProcesses,
+    TopMemory,
+    TopSwappers,
+    CpuByUser,
+    SystemOverview,
+    UsageHistory,
+    PerCoreUsage,
+    CoreHeatMap,
+    PerCoreHistory,
+    Sensors,
+    ProcessDisappeared,
+    SelectProcess,
+    GroupBy,
+    GroupNone,
+    GroupName,
+    GroupExecutable,
+    GroupTree,
+    FilterByName,
+    SearchCommandLine,
+    AllUsers,
+    Any,
+    MinMemory,
+    ProcessesMatch,
+    RefreshProcesses,
+    CopyVisiblePids,
+    ExportCsv,
+    SaveSnapshot,
+    LoadSnapshot,
+    SnapshotFile,
+    ExitReplay,
+    LightTheme,
+    DarkTheme,
+    SystemTheme,
+    Language,
+    HoverDelay,
+    RefreshEvery,
+    MaxRows,
+    KeepExitedRows,
+    NumberFormat,
+    TreeDepthLimit,
+    ConfirmBeforeKilling,
+    DashboardMemory,
+    MemoryUnit,
+    Profile,
+    ApplicationSettings,
+    CompactMode,
+    RefreshSection,
+    NumbersSection,
+    ProcessTableSection,
+    Columns,
+    ShowMemoryBars,
+    ZebraStriping,
+    RowGridlines,
+    ProcessTooltipsSection,
+    ShowName,
+    ShowExe,
+    ShowCmd,
+    SafetySection,
+    ScreenshotsSection,
+    SaveFolder,
+    PressEnterToSave,
+    WindowSection,
+    AccessibilitySection,
+    BackgroundSection,
+    MetricsEndpointSection,
+    DeveloperToolsSection,
+    ShareSettingsSection,
+    MinCpu,
+    StatusLabel,
+    KillProcess,
+    Events,
+    NoEvents,
+    EventStarted,
+    EventExited,
+    TreeLevels,
+    MemoryUsed,
+    MemoryTotalMinusAvailable,
+    ChooseProfile,
+    ProfileName,
+    SaveAsProfile,
+    Rename,
+    Delete,
+    CapProcessCpu,
+    ReadOnlyMode,
+    ReadOnlyForced,
+    PicturesFolder,
+    RememberWindowSize,
+    LiveWindowTitle,
+    StartInTray,
+    ReduceMotion,
+    ShowGraphs,
+    BackgroundSampling,
+    ServeMetrics,
+    Port,
+    MetricsUrls,
+    EnableDeveloperActions,
+    DebuggerCommand,
+    DebuggerCommandHint,
+    CopySettings,
+    PasteSettings,
+    CopyDiagnostics,
+}
+
+impl UiText {
+    fn text(self, language: Language) -> &'static str {
+        let (english, thai) = match self {
+            UiText::LoadingSettings => ("Loading settings...", "กำลังโหลดการตั้งค่า..."),
+            UiText::TabDashboard => ("Dashboard", "แดชบอร์ด"),
+            UiText::TabProcesses => ("Processes", "โปรเซส"),
+            UiText::TabNetwork => ("Network", "เครือข่าย"),
+            UiText::TabDisks => ("Disks", "ดิสก์"),
+            UiText::TabSettings => ("Settings", "การตั้งค่า"),
+            UiText::Screenshot => ("📷 Screenshot", "📷 จับภาพหน้าจอ"),
+            UiText::GoToPid => ("Go to PID", "ไปที่ PID"),
+            UiText::Refresh => ("⟳ Refresh", "⟳ รีเฟรช"),
+            UiText::Pause => ("⏸ Pause", "⏸ หยุดชั่วคราว"),
+            UiText::Resume => ("▶ Resume", "▶ ทำต่อ"),
+            UiText::Paused => ("⏸ Paused", "⏸ หยุดอยู่"),
+            UiText::AppTitle => ("System Monitor", "ตัวตรวจสอบระบบ"),
+            UiText::RealTime => ("🟢 Real-time", "🟢 เรียลไทม์"),
+            UiText::CpuUsage => ("CPU Usage", "การใช้ CPU"),
+            UiText::Memory => ("Memory", "หน่วยความจำ"),
+            UiText::Swap => ("Swap", "สวอป"),
+            UiText::CpuFrequency => ("CPU Frequency", "ความถี่ CPU"),
+            UiText::Cores => ("Cores", "คอร์"),
+            UiText::Load => ("Load (1 / 5 / 15 min)", "โหลด (1 / 5 / 15 นาที)"),
+            UiText::Uptime => ("Uptime", "เวลาทำงาน"),
+            UiText::NoSwapUsers => ("No processes are using swap", "ไม่มีโปรเซสที่ใช้สวอป"),
+            UiText::NoProcesses => ("No processes running", "ไม่มีโปรเซสที่กำลังทำงาน"),
+            UiText::TopMemory => ("Top Memory", "ใช้หน่วยความจำสูงสุด"),
+            UiText::TopSwappers => ("Top Swappers", "ใช้สวอปสูงสุด"),
+            UiText::CpuByUser => ("CPU by User", "CPU ตามผู้ใช้"),
+            UiText::SystemOverview => ("System Overview", "ภาพรวมระบบ"),
+            UiText::UsageHistory => ("Usage History", "ประวัติการใช้งาน"),
+            UiText::PerCoreUsage => ("Per-Core Usage", "การใช้งานแต่ละคอร์"),
+            UiText::CoreHeatMap => ("Core Heat Map", "แผนที่ความร้อนของคอร์"),
+            UiText::PerCoreHistory => ("Per-Core History", "ประวัติแต่ละคอร์"),
+            UiText::Sensors => ("Sensors", "เซนเซอร์"),
+            UiText::ProcessDisappeared => ("Process disappeared.", "โปรเซสหายไปแล้ว"),
+            UiText::SelectProcess => ("Select a process from the list", "เลือกโปรเซสจากรายการ"),
+            UiText::GroupBy => ("Group by:", "จัดกลุ่มตาม:"),
+            UiText::GroupNone => ("None", "ไม่จัดกลุ่ม"),
+            UiText::GroupName => ("Name", "ชื่อ"),
+            UiText::GroupExecutable => ("Executable", "ไฟล์โปรแกรม"),
+            UiText::GroupTree => ("Tree", "ต้นไม้"),
+            UiText::FilterByName => ("Filter by name", "กรองตามชื่อ"),
+            UiText::SearchCommandLine => ("Search in command line", "ค้นหาในบรรทัดคำสั่ง"),
+            UiText::AllUsers => ("All users", "ผู้ใช้ทั้งหมด"),
+            UiText::Any => ("any", "ทั้งหมด"),
+            UiText::MinMemory => ("Memory MiB ≥", "หน่วยความจำ MiB ≥"),
+            UiText::ProcessesMatch => ("{} of {} processes match", "ตรงกัน {} จาก {} โปรเซส"),
+            UiText::RefreshProcesses => ("Refresh Processes", "รีเฟรชโปรเซส"),
+            UiText::CopyVisiblePids => ("Copy Visible PIDs", "คัดลอก PID ที่แสดง"),
+            UiText::ExportCsv => ("Export Process List to CSV", "ส่งออกรายการโปรเซสเป็น CSV"),
+            UiText::SaveSnapshot => ("Save Snapshot", "บันทึกสแนปช็อต"),
+            UiText::LoadSnapshot => ("Load Snapshot", "โหลดสแนปช็อต"),
+            UiText::SnapshotFile => ("Snapshot file (newest if empty)", "ไฟล์สแนปช็อต (ล่าสุดถ้าเว้นว่าง)"),
+            UiText::ExitReplay => ("Exit Replay", "ออกจากการเล่นซ้ำ"),
+            UiText::LightTheme => ("Light Theme", "ธีมสว่าง"),
+            UiText::DarkTheme => ("Dark Theme", "ธีมมืด"),
+            UiText::SystemTheme => ("Follow System Theme", "ตามธีมของระบบ"),
+            UiText::Language => ("Language:", "ภาษา:"),
+            UiText::HoverDelay => ("Hover delay:", "หน่วงเวลาเมื่อชี้:"),
+            UiText::RefreshEvery => ("Refresh every:", "รีเฟรชทุก:"),
+            UiText::MaxRows => ("Max rows shown:", "จำนวนแถวสูงสุด:"),
+            UiText::KeepExitedRows => ("Keep exited rows for:", "เก็บแถวที่จบแล้วไว้:"),
+            UiText::NumberFormat => ("Number format:", "รูปแบบตัวเลข:"),
+            UiText::TreeDepthLimit => ("Tree depth limit:", "ความลึกสูงสุดของต้นไม้:"),
+            UiText::ConfirmBeforeKilling => ("Confirm before killing:", "ยืนยันก่อนปิดโปรเซส:"),
+            UiText::DashboardMemory => ("Dashboard memory:", "หน่วยความจำบนแดชบอร์ด:"),
+            UiText::MemoryUnit => ("Memory unit:", "หน่วยของหน่วยความจำ:"),
+            UiText::Profile => ("Profile:", "โปรไฟล์:"),
+            UiText::ApplicationSettings => ("Application Settings", "การตั้งค่าแอปพลิเคชัน"),
+            UiText::CompactMode => ("Compact mode (smaller cards and table rows)", "โหมดกะทัดรัด (การ์ดและแถวตารางเล็กลง)"),
+            UiText::RefreshSection => ("Refresh", "การรีเฟรช"),
+            UiText::NumbersSection => ("Numbers", "ตัวเลข"),
+            UiText::ProcessTableSection => ("Process Table", "ตารางโปรเซส"),
+            UiText::Columns => ("Columns", "คอลัมน์"),
+            UiText::ShowMemoryBars => ("Show memory share bars", "แสดงแถบสัดส่วนหน่วยความจำ"),
+            UiText::ZebraStriping => ("Zebra striping", "แถวสลับสี"),
+            UiText::RowGridlines => ("Row gridlines", "เส้นแบ่งแถว"),
+            UiText::ProcessTooltipsSection => ("Process Tooltips", "คำแนะนำของโปรเซส"),
+            UiText::ShowName => ("Show name", "แสดงชื่อ"),
+            UiText::ShowExe => ("Show executable path", "แสดงพาธไฟล์โปรแกรม"),
+            UiText::ShowCmd => ("Show command line", "แสดงบรรทัดคำสั่ง"),
+            UiText::SafetySection => ("Safety", "ความปลอดภัย"),
+            UiText::ScreenshotsSection => ("Screenshots", "ภาพหน้าจอ"),
+            UiText::SaveFolder => ("Save folder:", "โฟลเดอร์ที่บันทึก:"),
+            UiText::PressEnterToSave => ("(press Enter to save)", "(กด Enter เพื่อบันทึก)"),
+            UiText::WindowSection => ("Window", "หน้าต่าง"),
+            UiText::AccessibilitySection => ("Accessibility", "การช่วยการเข้าถึง"),
+            UiText::BackgroundSection => ("Background", "เบื้องหลัง"),
+            UiText::MetricsEndpointSection => ("Metrics Endpoint", "ปลายทางเมตริก"),
+            UiText::DeveloperToolsSection => ("Developer Tools", "เครื่องมือนักพัฒนา"),
+            UiText::ShareSettingsSection => ("Share Settings", "แชร์การตั้งค่า"),
+            UiText::MinCpu => ("CPU % ≥", "CPU % ≥"),
+            UiText::StatusLabel => ("Status:", "สถานะ:"),
+            UiText::KillProcess => ("Kill Process", "ปิดโปรเซส"),
+            UiText::Events => ("Events", "เหตุการณ์"),
+            UiText::NoEvents => ("No processes have started or exited yet", "ยังไม่มีโปรเซสที่เริ่มหรือจบการทำงาน"),
+            UiText::EventStarted => ("Started: {} ({})", "เริ่ม: {} ({})"),
+            UiText::EventExited => ("Exited: {} ({})", "จบ: {} ({})"),
+            UiText::TreeLevels => ("{} levels", "{} ระดับ"),
+            UiText::MemoryUsed => ("Used", "ที่ใช้"),
+            UiText::MemoryTotalMinusAvailable => ("Total − available", "ทั้งหมด − ที่ว่าง"),
+            UiText::ChooseProfile => ("Choose a profile", "เลือกโปรไฟล์"),
+            UiText::ProfileName => ("Profile name", "ชื่อโปรไฟล์"),
+            UiText::SaveAsProfile => ("Save current as profile", "บันทึกค่าปัจจุบันเป็นโปรไฟล์"),
+            UiText::Rename => ("Rename", "เปลี่ยนชื่อ"),
+            UiText::Delete => ("Delete", "ลบ"),
+            UiText::CapProcessCpu => ("Cap process CPU at core count × 100% (off shows raw readings)", "จำกัด CPU ของโปรเซสที่จำนวนคอร์ × 100% (ปิดเพื่อแสดงค่าดิบ)"),
+            UiText::ReadOnlyMode => ("Read-only mode (disable kill and other destructive actions)", "โหมดอ่านอย่างเดียว (ปิดการปิดโปรเซสและการกระทำที่ทำลายข้อมูล)"),
+            UiText::ReadOnlyForced => ("Forced on by the --read-only command line flag", "ถูกเปิดโดยตัวเลือก --read-only ในบรรทัดคำสั่ง"),
+            UiText::PicturesFolder => ("Pictures folder", "โฟลเดอร์รูปภาพ"),
+            UiText::RememberWindowSize => ("Remember window size per tab", "จำขนาดหน้าต่างแยกตามแท็บ"),
+            UiText::LiveWindowTitle => ("Show CPU and memory usage in the window title", "แสดงการใช้ CPU และหน่วยความจำในชื่อหน้าต่าง"),
+            UiText::StartInTray => ("Start in the system tray (closing the window hides it there)", "เริ่มในถาดระบบ (ปิดหน้าต่างเพื่อซ่อนไว้ที่นั่น)"),
+            UiText::ReduceMotion => ("Reduce motion (no animated value changes)", "ลดการเคลื่อนไหว (ไม่มีภาพเคลื่อนไหวเมื่อค่าเปลี่ยน)"),
+            UiText::ShowGraphs => ("Show history graphs on the dashboard", "แสดงกราฟประวัติบนแดชบอร์ด"),
+            UiText::BackgroundSampling => ("When minimized, keep recording history but pause the process list", "เมื่อย่อหน้าต่าง ให้บันทึกประวัติต่อแต่หยุดรายการโปรเซส"),
+            UiText::ServeMetrics => ("Serve metrics over HTTP on localhost", "ให้บริการเมตริกผ่าน HTTP บน localhost"),
+            UiText::Port => ("Port:", "พอร์ต:"),
+            UiText::MetricsUrls => ("http://127.0.0.1:{}/metrics (Prometheus), /metrics.json — press Enter to apply", "http://127.0.0.1:{}/metrics (Prometheus), /metrics.json — กด Enter เพื่อใช้"),
+            UiText::EnableDeveloperActions => ("Enable developer actions (attach a debugger to a process)", "เปิดการกระทำสำหรับนักพัฒนา (แนบดีบักเกอร์กับโปรเซส)"),
+            UiText::DebuggerCommand => ("Debugger command:", "คำสั่งดีบักเกอร์:"),
+            UiText::DebuggerCommandHint => ("({pid} is replaced, press Enter to save)", "({pid} จะถูกแทนที่ กด Enter เพื่อบันทึก)"),
+            UiText::CopySettings => ("Copy settings to clipboard", "คัดลอกการตั้งค่าไปยังคลิปบอร์ด"),
+            UiText::PasteSettings => ("Paste settings from clipboard", "วางการตั้งค่าจากคลิปบอร์ด"),
+            UiText::CopyDiagnostics => ("Copy diagnostics", "คัดลอกข้อมูลวินิจฉัย"),
+        };
+        match language {
+            Language::English => english,
+            Language::Thai => thai,
+        }
+    }
+}
+
+/// How digits are grouped when formatting large numbers (memory sizes, counts, PIDs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum NumberGrouping {
+    /// 1,234,567.8
+    Comma,
+    /// 1.234.567,8
+    Period,
+    /// 1 234 567,8
+    Space,
+    /// 1234567.8
+    None,
+}
+
+impl NumberGrouping {
+    const ALL: [NumberGrouping; 4] = [
+        NumberGrouping::Comma,
+        NumberGrouping::Period,
+        NumberGrouping::Space,
+        NumberGrouping::None,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            NumberGrouping::Comma => "1,234.5",
+            NumberGrouping::Period => "1.234,5",
+            NumberGrouping::Space => "1 234,5",
+            NumberGrouping::None => "1234.5",
+        }
+    }
+
+    fn format(self) -> CustomFormat {
+        let (separator, decimal) = match self {
+            NumberGrouping::Comma => (",", "."),
+            NumberGrouping::Period => (".", ","),
+            NumberGrouping::Space => (" ", ","),
+            NumberGrouping::None => ("", "."),
+        };
+        CustomFormat::builder()
+            .grouping(if self == NumberGrouping::None { Grouping::Posix } else { Grouping::Standard })
+            .separator(separator)
+            .decimal(decimal)
+            .build()
+            .unwrap_or_default()
+    }
+}
+
+/// When a kill asks for confirmation before it goes ahead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum KillConfirmMode {
+    Always,
+    /// Single kills go through immediately; "Kill all" on a group header still confirms.
+    BulkOnly,
+    Never,
+}
+
+impl KillConfirmMode {
+    const ALL: [KillConfirmMode; 3] = [KillConfirmMode::Always, KillConfirmMode::BulkOnly, KillConfirmMode::Never];
+
+    fn label(self) -> &'static str {
+        match self {
+            KillConfirmMode::Always => "Always",
+            KillConfirmMode::BulkOnly => "Bulk kills only",
+            KillConfirmMode::Never => "Never",
+        }
+    }
+
+    fn requires_confirmation(self, bulk: bool) -> bool {
+        match self {
+            KillConfirmMode::Always => true,
+            KillConfirmMode::BulkOnly => bulk,
+            KillConfirmMode::Never => false,
+        }
+    }
+}
+
+/// Which figure the dashboard reports as "memory in use".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum MemoryMetric {
+    /// sysinfo's `used_memory()`.
+    Used,
+    /// Total minus available memory, i.e. what can't be reclaimed on demand.
+    TotalMinusAvailable,
+}
+
+impl MemoryMetric {
+    fn label(self) -> UiText {
+        match self {
+            MemoryMetric::Used => UiText::MemoryUsed,
+            MemoryMetric::TotalMinusAvailable => UiText::MemoryTotalMinusAvailable,
+        }
+    }
+
+    /// Bytes in use under this metric, plus the metric actually applied. Platforms that
+    /// don't report available memory fall back to `used_memory()`.
+    fn read(self, sys: &System) -> (u64, MemoryMetric) {
+        match self {
+            MemoryMetric::TotalMinusAvailable if sys.available_memory() > 0 => {
+                (sys.total_memory().saturating_sub(sys.available_memory()), self)
+            }
+            _ => (sys.used_memory(), MemoryMetric::Used),
+        }
+    }
+
+    /// Bytes in use under the other metric, for showing alongside. `None` where the platform
+    /// doesn't report available memory, since both would be `used_memory()`.
+    fn read_other(self, sys: &System) -> Option<(u64, MemoryMetric)> {
+        let other = match self {
+            MemoryMetric::Used => MemoryMetric::TotalMinusAvailable,
+            MemoryMetric::TotalMinusAvailable => MemoryMetric::Used,
+        };
+        (sys.available_memory() > 0).then(|| (other.read(sys).0, other))
+    }
+}
+
+/// Unit memory sizes are shown in across the dashboard, process table and detail pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum MemoryUnit {
+    /// Each value in the largest binary unit that keeps it at or above 1, e.g. "40.0 KiB".
+    Auto,
+    Bytes,
+    /// 1024² bytes.
+    MiB,
+    /// 1024³ bytes.
+    GiB,
+    /// 1000³ bytes, as drive and RAM vendors count.
+    GB,
+}
+
+impl MemoryUnit {
+    const ALL: [MemoryUnit; 5] = [MemoryUnit::Auto, MemoryUnit::Bytes, MemoryUnit::MiB, MemoryUnit::GiB, MemoryUnit::GB];
+
+    fn label(self) -> &'static str {
+        match self {
+            MemoryUnit::Auto => "Auto",
+            MemoryUnit::Bytes => "B",
+            MemoryUnit::MiB => "MiB",
+            MemoryUnit::GiB => "GiB",
+            MemoryUnit::GB => "GB",
+        }
+    }
+
+    /// `bytes` converted to this unit, with the decimals and unit label to show it with.
+    fn scale(self, bytes: f64) -> (f64, usize, &'static str) {
+        match self {
+            MemoryUnit::Auto => {
+                const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+                let mut value = bytes.max(0.0);
+                let mut unit = 0;
+                while value >= 1024.0 && unit < UNITS.len() - 1 {
+                    value /= 1024.0;
+                    unit += 1;
+                }
+                (value, if unit == 0 { 0 } else { 1 }, UNITS[unit])
+            }
+            MemoryUnit::Bytes => (bytes, 0, "B"),
+            MemoryUnit::MiB => (bytes / (1024.0 * 1024.0), 1, "MiB"),
+            MemoryUnit::GiB => (bytes / (1024.0 * 1024.0 * 1024.0), 2, "GiB"),
+            MemoryUnit::GB => (bytes / 1_000_000_000.0, 2, "GB"),
+        }
+    }
+}
+
+/// Formats a byte count in `unit`, with digits grouped and the decimal mark set by `grouping`.
+fn format_memory(bytes: u64, unit: MemoryUnit, grouping: NumberGrouping) -> String {
+    let (value, decimals, label) = unit.scale(bytes as f64);
+    format!("{} {}", format_grouped(value, decimals, grouping), label)
+}
+
+/// Used where a figure is always shown in GiB, such as the diagnostics report.
+const BYTES_PER_GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// A named set of setting overrides, stored as the JSON fields it changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SettingsProfile {
+    name: String,
+    settings: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SettingsProfile {
+    /// Fields a profile never captures or changes: the profile list itself, per-machine
+    /// state, and safety switches that must stay under explicit user control.
+    const EXCLUDED_FIELDS: [&'static str; 9] = [
+        "profiles",
+        "active_profile",
+        "tab_window_sizes",
+        "last_active_tab",
+        "last_window_size",
+        "last_seen_version",
+        "read_only",
+        "auto_rules_enabled",
+        "metrics_endpoint_enabled",
+    ];
+
+    fn capture(name: &str, settings: &AppSettings) -> Result<Self, String> {
+        let serde_json::Value::Object(mut fields) = serde_json::to_value(settings).map_err(|e| e.to_string())? else {
+            return Err("settings did not serialize to an object".to_string());
+        };
+        fields.retain(|key, _| !Self::EXCLUDED_FIELDS.contains(&key.as_str()));
+        Ok(Self { name: name.to_string(), settings: fields })
+    }
+
+    /// `current` with this profile's fields applied on top.
+    fn apply(&self, current: &AppSettings) -> Result<AppSettings, String> {
+        let mut value = serde_json::to_value(current).map_err(|e| e.to_string())?;
+        if let serde_json::Value::Object(fields) = &mut value {
+            for (key, field) in &self.settings {
+                if !Self::EXCLUDED_FIELDS.contains(&key.as_str()) {
+                    fields.insert(key.clone(), field.clone());
+                }
+            }
+        }
+        let settings: AppSettings = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Presets that are always offered and can't be renamed or deleted.
+    fn built_in() -> Vec<SettingsProfile> {
+        let preset = |name: &str, fields: serde_json::Value| SettingsProfile {
+            name: name.to_string(),
+            settings: match fields {
+                serde_json::Value::Object(fields) => fields,
+                _ => serde_json::Map::new(),
+            },
+        };
+        vec![
+            preset("Battery saver", serde_json::json!({
+                "process_row_limit": 100,
+                "refresh_interval_secs": 5,
+                "show_memory_bars": false,
+                "reduce_motion": true,
+                "show_graphs": false,
+                "background_sampling": true,
+            })),
+            preset("Power user", serde_json::json!({
+                "process_row_limit": 1000,
+                "refresh_interval_secs": 1,
+                "show_memory_bars": true,
+                "visible_columns": SortColumn::ALL,
+                "reduce_motion": false,
+                "show_graphs": true,
+            })),
+        ]
+    }
+}
+
+/// Buttons that can appear in the process detail pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum DetailAction {
+    Kill,
+    /// Stops the process, or continues it when it is already stopped.
+    Suspend,
+    /// Raises or lowers the process niceness by `NICE_STEP`.
+    Renice,
+    Copy,
+    OpenLocation,
+    /// Starts another instance from the process's command line.
+    Relaunch,
+    /// Kills the process and starts it again with the same executable and arguments.
+    Restart,
+    PopOutGraph,
+    Compare,
+}
+
+impl DetailAction {
+    const ALL: [DetailAction; 9] = [
+        DetailAction::Kill,
+        DetailAction::Suspend,
+        DetailAction::Renice,
+        DetailAction::Copy,
+        DetailAction::OpenLocation,
+        DetailAction::Relaunch,
+        DetailAction::Restart,
+        DetailAction::PopOutGraph,
+        DetailAction::Compare,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            DetailAction::Kill => "Kill",
+            DetailAction::Suspend => "Suspend / resume",
+            DetailAction::Renice => "Adjust priority",
+            DetailAction::Copy => "Copy details",
+            DetailAction::OpenLocation => "Open file location",
+            DetailAction::Relaunch => "Relaunch",
+            DetailAction::Restart => "Restart",
+            DetailAction::PopOutGraph => "Pop out graph",
+            DetailAction::Compare => "Pin for comparison",
+        }
+    }
+
+    /// Actions that change the target process or spawn new ones, and are therefore disabled
+    /// in read-only mode.
+    fn is_destructive(self) -> bool {
+        matches!(
+            self,
+            DetailAction::Kill | DetailAction::Suspend | DetailAction::Renice | DetailAction::Relaunch | DetailAction::Restart
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum RuleMetric {
+    /// Per-process CPU usage in percent.
+    Cpu,
+    /// Resident memory in MiB.
+    Memory,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum RuleAction {
+    Alert,
+    Kill,
+}
+
+/// "If a process named like `name_pattern` keeps `metric` above `threshold` for
+/// `duration_secs`, perform `action`."
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AutoRule {
+    name_pattern: String,
+    metric: RuleMetric,
+    threshold: f64,
+    duration_secs: u64,
+    action: RuleAction,
+}
+
+impl AutoRule {
+    fn describe(&self) -> String {
+        let (metric, unit) = match self.metric {
+            RuleMetric::Cpu => ("CPU", "%"),
+            RuleMetric::Memory => ("memory", " MiB"),
+        };
+        let action = match self.action {
+            RuleAction::Alert => "alert",
+            RuleAction::Kill => "kill",
+        };
+        format!(
+            "If \"{}\" {} > {}{} for {}s → {}",
+            self.name_pattern, metric, self.threshold, unit, self.duration_secs, action
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 struct AppSettings {
     theme: ThemeChoice,
+    /// Defaults to the system locale when the config doesn't say.
+    language: Language,
+    /// Custom primary colour as RGB in 0.0–1.0; `None` keeps the theme's own palette.
+    accent: Option<[f32; 3]>,
+    tooltip_delay_ms: u64,
+    tooltip_show_name: bool,
+    tooltip_show_exe: bool,
+    tooltip_show_cmd: bool,
+    remember_window_size_per_tab: bool,
+    tab_window_sizes: HashMap<Tab, WindowSize>,
+    /// Desktop notifications when system CPU or memory stays above its threshold.
+    usage_alerts_enabled: bool,
+    /// System CPU % that must be exceeded for `ALERT_SUSTAIN_SAMPLES` samples in a row.
+    cpu_alert_threshold: f32,
+    /// Memory % (of total) for the same sustained-usage alert.
+    mem_alert_threshold: f32,
+    /// Tab the app reopens on.
+    last_active_tab: Tab,
+    /// Main window size at the last resize, applied when the app next starts.
+    last_window_size: Option<WindowSize>,
+    process_row_limit: usize,
+    read_only: bool,
+    number_grouping: NumberGrouping,
+    process_grace_period_ms: u64,
+    /// Process table columns that are shown; never empty.
+    visible_columns: std::collections::HashSet<SortColumn>,
+    auto_rules_enabled: bool,
+    auto_rules: Vec<AutoRule>,
+    /// Folder for screenshots; empty means the user's Pictures folder.
+    screenshot_dir: String,
+    auto_export_enabled: bool,
+    auto_export_interval_mins: u64,
+    /// Seconds between metric samples; the process table never refreshes faster than this.
+    refresh_interval_secs: u64,
+    auto_export_format: ExportFormat,
+    /// Folder for scheduled exports; empty means `<data dir>/exports`.
+    auto_export_dir: String,
+    /// Scheduled export files kept; older ones beyond this count are deleted.
+    export_retention_max_files: usize,
+    /// Scheduled export files older than this are deleted.
+    export_retention_max_age_days: u64,
+    grouping_mode: GroupingMode,
+    /// Tree view rows deeper than this are folded under a "+N more" button.
+    tree_depth_limit: usize,
+    show_memory_bars: bool,
+    /// Tighter padding and smaller card values for small screens (see `Sizing::COMPACT`).
+    compact_mode: bool,
+    /// Alternating row backgrounds in the process table.
+    zebra_striping: bool,
+    /// Thin separator lines between process table rows.
+    row_gridlines: bool,
+    memory_metric: MemoryMetric,
+    memory_unit: MemoryUnit,
+    /// Caps per-process CPU at core count × 100%; turn off to see raw readings.
+    cap_process_cpu: bool,
+    kill_confirm_mode: KillConfirmMode,
+    live_window_title: bool,
+    /// Detail pane buttons, in display order.
+    detail_actions: Vec<DetailAction>,
+    /// Shows developer-only actions such as attaching a debugger.
+    developer_tools_enabled: bool,
+    /// While the main window is minimized, keep sampling metrics but skip process list
+    /// rebuilds and view work.
+    background_sampling: bool,
+    /// Disables animated transitions such as easing of dashboard card values.
+    reduce_motion: bool,
+    /// Draws the dashboard's history charts and card sparklines.
+    show_graphs: bool,
+    /// Starts with only the tray icon showing, and closing the window hides it to the tray.
+    /// Has no effect without the `tray` feature.
+    start_in_tray: bool,
+    /// Serves current metrics over HTTP on localhost; off by default.
+    metrics_endpoint_enabled: bool,
+    metrics_port: u16,
+    /// User-saved profiles; the built-in presets are not stored here.
+    profiles: Vec<SettingsProfile>,
+    /// Name of the profile last applied, if any.
+    active_profile: Option<String>,
+    /// App version whose changelog the user last dismissed.
+    last_seen_version: String,
+    /// Command used to attach a debugger; `{pid}` is replaced with the selected PID.
+    debugger_command: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct WindowSize {
+    width: f32,
+    height: f32,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
-        Self { theme: ThemeChoice::Dark }
+        Self {
+            theme: ThemeChoice::Dark,
+            language: Language::English,
+            accent: None,
+            tooltip_delay_ms: 500,
+            tooltip_show_name: true,
+            tooltip_show_exe: true,
+            tooltip_show_cmd: false,
+            remember_window_size_per_tab: false,
+            tab_window_sizes: HashMap::new(),
+            usage_alerts_enabled: false,
+            cpu_alert_threshold: 90.0,
+            mem_alert_threshold: 90.0,
+            last_active_tab: Tab::Dashboard,
+            last_window_size: None,
+            process_row_limit: 200,
+            read_only: false,
+            number_grouping: NumberGrouping::Comma,
+            process_grace_period_ms: 2000,
+            // Start time is opt-in; the run time column usually says enough.
+            visible_columns: SortColumn::ALL
+                .into_iter()
+                .filter(|c| !matches!(c, SortColumn::StartTime | SortColumn::Status | SortColumn::RunTime))
+                .collect(),
+            auto_rules_enabled: false,
+            auto_rules: Vec::new(),
+            screenshot_dir: String::new(),
+            auto_export_enabled: false,
+            auto_export_interval_mins: 10,
+            auto_export_format: ExportFormat::Csv,
+            auto_export_dir: String::new(),
+            export_retention_max_files: 1000,
+            refresh_interval_secs: 1,
+            export_retention_max_age_days: 90,
+            grouping_mode: GroupingMode::None,
+            tree_depth_limit: 6,
+            show_memory_bars: false,
+            compact_mode: false,
+            zebra_striping: false,
+            row_gridlines: false,
+            memory_metric: MemoryMetric::Used,
+            memory_unit: MemoryUnit::Auto,
+            cap_process_cpu: true,
+            kill_confirm_mode: KillConfirmMode::Always,
+            live_window_title: true,
+            detail_actions: vec![
+                DetailAction::PopOutGraph,
+                DetailAction::Compare,
+                DetailAction::Copy,
+                DetailAction::Kill,
+            ],
+            developer_tools_enabled: false,
+            reduce_motion: false,
+            show_graphs: true,
+            start_in_tray: false,
+            background_sampling: true,
+            last_seen_version: String::new(),
+            profiles: Vec::new(),
+            active_profile: None,
+            metrics_endpoint_enabled: false,
+            metrics_port: 9184,
+            debugger_command: default_debugger_command().to_string(),
+        }
     }
 }
 
@@ -49,643 +887,7186 @@ impl AppSettings {
         })
     }
 
-    async fn load() -> Result<Self, String> {
-        if let Some(path) = Self::config_path() {
-            if path.exists() {
-                let content = tokio::fs::read_to_string(path)
-                    .await
-                    .map_err(|e| e.to_string())?;
-                serde_json::from_str(&content).map_err(|e| e.to_string())
-            } else {
-                Ok(Self::default())
+    /// Loads the settings, plus the backup path when a corrupt file had to be replaced.
+    async fn load() -> Result<(Self, Option<PathBuf>), String> {
+        let Some(path) = Self::config_path() else {
+            return Err("Could not find config directory".to_string());
+        };
+        tokio::task::spawn_blocking(move || Self::load_from(&path))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+
+    /// Reads `path`, falling back to defaults when it doesn't exist. A file that isn't valid
+    /// settings JSON is moved aside to `<name>.bak` and replaced with the defaults, so the
+    /// same error doesn't come back on every launch.
+    fn load_from(path: &std::path::Path) -> Result<(Self, Option<PathBuf>), String> {
+        if !path.exists() {
+            let settings = Self::first_run();
+            // Written right away so the locale is only detected once, on the first launch.
+            if let Err(e) = settings.to_json().and_then(|json| write_creating_dirs(path, &json)) {
+                tracing::warn!("Could not write first-run settings to {:?}: {}", path, e);
+            }
+            return Ok((settings, None));
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        match serde_json::from_str::<Self>(&content) {
+            Ok(mut settings) => {
+                // A hand-edited file with no columns would leave an empty table and no way back.
+                if settings.visible_columns.is_empty() {
+                    settings.visible_columns = Self::default().visible_columns;
+                }
+                Ok((settings, None))
+            }
+            Err(e) => {
+                tracing::warn!("Settings file {:?} is corrupt ({}), resetting to defaults", path, e);
+                let mut backup_name = path.file_name().unwrap_or_default().to_os_string();
+                backup_name.push(".bak");
+                let backup = path.with_file_name(backup_name);
+                std::fs::rename(path, &backup).map_err(|e| e.to_string())?;
+                let settings = Self::default();
+                std::fs::write(path, settings.to_json()?).map_err(|e| e.to_string())?;
+                Ok((settings, Some(backup)))
             }
-        } else {
-            Err("Could not find config directory".to_string())
         }
     }
 
-    async fn save(self) -> Result<(), String> {
-        if let Some(path) = Self::config_path() {
-            if let Some(parent) = path.parent() {
-                if !parent.exists() {
-                    tokio::fs::create_dir_all(parent)
-                        .await
-                        .map_err(|e| e.to_string())?;
-                }
+    /// Defaults for a fresh install: the language follows the system locale, and there are
+    /// no earlier version's changes to announce.
+    fn first_run() -> Self {
+        Self {
+            language: Language::detect(),
+            last_seen_version: APP_VERSION.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Settings read synchronously before the UI starts, for what the window needs up front.
+    fn read_saved() -> Option<Self> {
+        let content = std::fs::read_to_string(Self::config_path()?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Window size from the saved settings, read synchronously because the window is
+    /// created before the async `load` runs.
+    fn saved_window_size() -> Option<WindowSize> {
+        Self::read_saved()?.last_window_size.filter(|size| size.width > 0.0 && size.height > 0.0)
+    }
+
+    fn backup_path() -> Option<PathBuf> {
+        Self::config_path().map(|path| path.with_file_name("settings.backup.json"))
+    }
+
+    fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// Copy with user-specific paths and commands blanked out, for sharing in diagnostics.
+    fn redacted(&self) -> Self {
+        let redact = |value: &str| if value.trim().is_empty() { String::new() } else { "<redacted>".to_string() };
+        Self {
+            screenshot_dir: redact(&self.screenshot_dir),
+            auto_export_dir: redact(&self.auto_export_dir),
+            debugger_command: redact(&self.debugger_command),
+            ..self.clone()
+        }
+    }
+
+    fn from_json(content: &str) -> Result<Self, String> {
+        let settings: Self = serde_json::from_str(content.trim()).map_err(|e| e.to_string())?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.tooltip_delay_ms > 10_000 {
+            return Err("tooltip_delay_ms must be at most 10000".to_string());
+        }
+        if self.process_row_limit == 0 {
+            return Err("process_row_limit must be greater than 0".to_string());
+        }
+        if self.visible_columns.is_empty() {
+            return Err("visible_columns must list at least one column".to_string());
+        }
+        for threshold in [self.cpu_alert_threshold, self.mem_alert_threshold] {
+            if !ALERT_THRESHOLD_RANGE.contains(&threshold) {
+                return Err(format!(
+                    "alert thresholds must be between {} and {}",
+                    ALERT_THRESHOLD_RANGE.start(),
+                    ALERT_THRESHOLD_RANGE.end()
+                ));
+            }
+        }
+        if let Some(accent) = self.accent {
+            if accent.iter().any(|channel| !(0.0..=1.0).contains(channel)) {
+                return Err("accent channels must be between 0 and 1".to_string());
+            }
+        }
+        if self.refresh_interval_secs == 0 {
+            return Err("refresh_interval_secs must be greater than 0".to_string());
+        }
+        if self.auto_export_interval_mins == 0 {
+            return Err("auto_export_interval_mins must be greater than 0".to_string());
+        }
+        for rule in &self.auto_rules {
+            if rule.name_pattern.trim().is_empty() {
+                return Err("auto rule name_pattern must not be empty".to_string());
+            }
+            if !rule.threshold.is_finite() || rule.threshold < 0.0 {
+                return Err("auto rule threshold must be a non-negative number".to_string());
             }
-            let content = serde_json::to_string_pretty(&self).map_err(|e| e.to_string())?;
-            tokio::fs::write(path, content)
-                .await
-                .map_err(|e| e.to_string())
-        } else {
-            Err("Could not find config directory".to_string())
         }
+        Ok(())
+    }
+
+    async fn backup(self) -> Result<(), String> {
+        let path = Self::backup_path().ok_or("Could not find config directory")?;
+        let content = self.to_json()?;
+        tokio::task::spawn_blocking(move || write_creating_dirs(&path, &content))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+
+    /// Blocking variant of `save` for when there is no runtime left to await on.
+    fn save_now(&self) -> Result<(), String> {
+        let path = Self::config_path().ok_or("Could not find config directory")?;
+        write_creating_dirs(&path, &self.to_json()?)
+    }
+
+    async fn save(self) -> Result<(), String> {
+        tokio::task::spawn_blocking(move || self.save_now())
+            .await
+            .map_err(|e| e.to_string())?
     }
 }
 
+/// The app's local data folder, where exports and snapshots go by default.
+fn data_dir() -> Option<PathBuf> {
+    ProjectDirs::from("com", "YourOrg", "SystemMonitor").map(|dirs| dirs.data_local_dir().to_path_buf())
+}
+
+/// Writes `content` to `path`, creating any missing parent directories first.
+fn write_creating_dirs(path: &std::path::Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, content).map_err(|e| e.to_string())
+}
+
 pub fn main() -> iced::Result {
     tracing_subscriber::fmt::init();
     tracing::info!("Starting System Utilities Application");
-    App::run(Settings::default())
+    let flags = StartupFlags {
+        read_only: std::env::args().any(|arg| arg == "--read-only"),
+        start_in_tray: cfg!(feature = "tray") && AppSettings::read_saved().is_some_and(|saved| saved.start_in_tray),
+    };
+    if flags.read_only {
+        tracing::info!("Read-only mode forced by --read-only flag");
+    }
+    let mut settings = Settings::with_flags(flags);
+    if let Some(size) = AppSettings::saved_window_size() {
+        settings.window.size = Size::new(size.width, size.height);
+    }
+    // Closing may only hide to the tray, so the app decides what a close request does.
+    settings.window.exit_on_close_request = false;
+    // Start hidden when the tray takes over; the window is shown again once settings have
+    // loaded if the tray icon couldn't be created.
+    settings.window.visible = !flags.start_in_tray;
+    App::run(settings)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Options parsed from the command line before the UI starts.
+#[derive(Debug, Clone, Copy, Default)]
+struct StartupFlags {
+    read_only: bool,
+    /// The saved settings ask to start hidden in the tray (only with the `tray` feature).
+    start_in_tray: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 enum Tab {
     Dashboard,
     Processes,
+    Network,
+    Disks,
     Settings,
 }
 
+impl Tab {
+    /// Tabs in tab bar order; Ctrl+1 selects the first.
+    const ALL: [Tab; 5] = [Tab::Dashboard, Tab::Processes, Tab::Network, Tab::Disks, Tab::Settings];
+}
+
+/// Space on one mounted filesystem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DiskData {
+    name: String,
+    mount_point: String,
+    file_system: String,
+    total: u64,
+    available: u64,
+    removable: bool,
+}
+
+impl DiskData {
+    fn used_percent(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.total.saturating_sub(self.available) as f64 / self.total as f64 * 100.0) as f32
+    }
+}
+
+/// Latest reading of one temperature sensor.
+#[derive(Debug, Clone)]
+struct SensorData {
+    label: String,
+    temperature: f32,
+    critical: Option<f32>,
+}
+
+impl SensorData {
+    fn read_all(components: &Components) -> Vec<SensorData> {
+        components.list().iter()
+            .map(|component| SensorData {
+                label: component.label().to_string(),
+                temperature: component.temperature(),
+                critical: component.critical(),
+            })
+            .filter(|sensor| sensor.temperature.is_finite())
+            .collect()
+    }
+
+    fn is_critical(&self) -> bool {
+        self.critical.is_some_and(|critical| critical > 0.0 && self.temperature >= critical)
+    }
+}
+
+/// Utilization and VRAM of one GPU. Only populated when built with the `gpu` feature.
+#[derive(Debug, Clone)]
+struct GpuData {
+    name: String,
+    utilization_percent: u32,
+    memory_used: u64,
+    memory_total: u64,
+}
+
+/// Throughput of one network interface over the last refresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NetworkData {
+    name: String,
+    rx_per_sec: f64,
+    tx_per_sec: f64,
+    total_rx: u64,
+    total_tx: u64,
+}
+
 #[derive(Debug, Clone)]
 struct ProcessData { 
     pid: Pid, 
     name: String, 
     cpu_usage: f32, 
-    memory: u64 
+    memory: u64,
+    swap: Option<u64>,
+    status: ProcessStatus,
+    exe: Option<String>,
+    /// Arguments joined with spaces, searched when "Search in command line" is on.
+    cmd: String,
+    /// Resolved account name of the owner; `None` when the UID has no matching user.
+    user: Option<String>,
+    parent: Option<Pid>,
+    /// Set while the row is kept around after its PID disappeared (churn grace period).
+    exited_at: Option<Instant>,
+    /// Bytes per second read from and written to disk since the previous process refresh.
+    disk_read_rate: f64,
+    disk_write_rate: f64,
+    /// Seconds since the process started, `None` when its start time couldn't be read.
+    run_time: Option<u64>,
+    /// Unix timestamp the process started at, 0 when unknown (sysinfo's convention).
+    start_time: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct SystemData { 
     cpu_usage: f32, 
-    memory_used: f64, 
-    memory_total: f64, 
+    /// Memory and swap figures are in bytes.
+    memory_used: u64, 
+    /// How `memory_used` was computed, shown next to the memory card title.
+    memory_metric: MemoryMetric,
+    /// The other metric's reading, where the platform tells them apart.
+    memory_other: Option<(u64, MemoryMetric)>,
+    memory_total: u64, 
+    swap_used: u64,
+    swap_total: u64,
+    /// Latest usage of each logical core, in `System::cpus()` order.
+    per_core_usage: Vec<f32>,
+    /// Current frequency of each logical core in MHz; all zero where the platform doesn't say.
+    per_core_frequency: Vec<u64>,
+    /// Highest frequency any core can reach in MHz, when the platform exposes it.
+    max_frequency: Option<u64>,
+    /// Seconds since boot and the boot time as a Unix timestamp, from `System::uptime`/`boot_time`.
+    uptime_secs: u64,
+    boot_time: u64,
+    /// 1, 5 and 15 minute load averages; `None` off Unix, where sysinfo only reports zeros.
+    load_average: Option<[f64; 3]>,
     process_count: usize 
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct ProcessExportData {
-    pid: usize,
-    name: String,
-    cpu_usage: f32,
-    memory_bytes: u64,
+impl SystemData {
+    fn memory_percent(&self) -> f32 {
+        percent_of(self.memory_used, self.memory_total)
+    }
+
+    fn swap_percent(&self) -> f32 {
+        percent_of(self.swap_used, self.swap_total)
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum NotificationLevel { Success, Error }
+/// `part` as a percentage of `total`, 0 when there is no total.
+fn percent_of(part: u64, total: u64) -> f32 {
+    if total > 0 { (part as f64 / total as f64 * 100.0) as f32 } else { 0.0 }
+}
+
+/// Snapshot of a process taken when it was selected, used for before/after deltas.
+#[derive(Debug, Clone, Copy)]
+struct SelectionBaseline {
+    pid: Pid,
+    selected_at: Instant,
+    cpu_usage: f32,
+    memory: u64,
+}
 
+/// In-progress values of the "add rule" form in the settings tab.
 #[derive(Debug, Clone)]
-struct StatusMessage { 
-    message: String, 
-    level: NotificationLevel 
+struct RuleDraft {
+    name_pattern: String,
+    metric: RuleMetric,
+    threshold: String,
+    duration_secs: String,
+    action: RuleAction,
 }
 
-impl StatusMessage {
-    fn success(message: &str) -> Self { 
-        Self { 
-            message: message.to_string(), 
-            level: NotificationLevel::Success 
-        } 
+impl Default for RuleDraft {
+    fn default() -> Self {
+        Self {
+            name_pattern: String::new(),
+            metric: RuleMetric::Memory,
+            threshold: String::new(),
+            duration_secs: "30".to_string(),
+            action: RuleAction::Alert,
+        }
     }
-    fn error(message: &str) -> Self { 
-        Self { 
+}
+
+impl RuleDraft {
+    fn to_rule(&self) -> Result<AutoRule, String> {
+        let name_pattern = self.name_pattern.trim().to_string();
+        if name_pattern.is_empty() {
+            return Err("Rule needs a process name pattern".to_string());
+        }
+        let threshold: f64 = self.threshold.trim().parse()
+            .map_err(|_| "Threshold must be a number".to_string())?;
+        if threshold < 0.0 {
+            return Err("Threshold must not be negative".to_string());
+        }
+        let duration_secs: u64 = self.duration_secs.trim().parse()
+            .map_err(|_| "Duration must be a whole number of seconds".to_string())?;
+        Ok(AutoRule { name_pattern, metric: self.metric, threshold, duration_secs, action: self.action })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum RuleDraftField {
+    NamePattern(String),
+    Metric(RuleMetric),
+    Threshold(String),
+    Duration(String),
+    Action(RuleAction),
+}
+
+/// Tracks how long one process has continuously breached one rule.
+#[derive(Debug, Clone, Copy)]
+struct RuleBreach {
+    since: Instant,
+    fired: bool,
+}
+
+/// Process table column, used both for ordering rows and for choosing which columns show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum SortColumn {
+    Pid,
+    Name,
+    Cpu,
+    Memory,
+    Swap,
+    DiskRead,
+    DiskWrite,
+    StartTime,
+    /// Owner; only shown when the table mixes several users.
+    User,
+    Status,
+    /// Elapsed run time.
+    RunTime,
+}
+
+impl SortColumn {
+    /// Columns in table order.
+    const ALL: [SortColumn; 11] = [
+        SortColumn::Pid,
+        SortColumn::Name,
+        SortColumn::Cpu,
+        SortColumn::Memory,
+        SortColumn::Swap,
+        SortColumn::DiskRead,
+        SortColumn::DiskWrite,
+        SortColumn::StartTime,
+        SortColumn::User,
+        SortColumn::Status,
+        SortColumn::RunTime,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SortColumn::Pid => "PID",
+            SortColumn::Name => "Name",
+            SortColumn::Cpu => "CPU %",
+            SortColumn::Memory => "Memory",
+            SortColumn::Swap => "Swap",
+            SortColumn::DiskRead => "Read/s",
+            SortColumn::DiskWrite => "Write/s",
+            SortColumn::StartTime => "Started",
+            SortColumn::User => "User",
+            SortColumn::Status => "Status",
+            SortColumn::RunTime => "Running",
+        }
+    }
+
+    /// Direction used when the column is first clicked: names, PIDs and other text read
+    /// top-down, usage columns put the heaviest processes first.
+    fn default_ascending(self) -> bool {
+        matches!(self, SortColumn::Pid | SortColumn::Name | SortColumn::User | SortColumn::Status)
+    }
+}
+
+/// Orders the table rows by `column`, breaking ties by PID so rows don't jump around
+/// between refreshes.
+fn sort_rows(rows: &mut [&ProcessData], column: SortColumn, ascending: bool) {
+    // Text keys are built once per row rather than twice per comparison.
+    let text_key: Option<fn(&ProcessData) -> String> = match column {
+        SortColumn::Name => Some(|p| p.name.to_lowercase()),
+        SortColumn::Status => Some(|p| p.status.to_string()),
+        _ => None,
+    };
+    if let Some(key) = text_key {
+        if ascending {
+            rows.sort_by_cached_key(|p| (key(p), p.pid));
+        } else {
+            rows.sort_by_cached_key(|p| (std::cmp::Reverse(key(p)), p.pid));
+        }
+        return;
+    }
+    rows.sort_by(|a, b| {
+        let ordering = match column {
+            SortColumn::Pid | SortColumn::Name | SortColumn::Status => a.pid.cmp(&b.pid),
+            SortColumn::Cpu => a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            SortColumn::Memory => a.memory.cmp(&b.memory),
+            SortColumn::Swap => a.swap.cmp(&b.swap),
+            SortColumn::DiskRead => a.disk_read_rate.partial_cmp(&b.disk_read_rate).unwrap_or(std::cmp::Ordering::Equal),
+            SortColumn::DiskWrite => a.disk_write_rate.partial_cmp(&b.disk_write_rate).unwrap_or(std::cmp::Ordering::Equal),
+            SortColumn::StartTime => a.start_time.cmp(&b.start_time),
+            SortColumn::User => a.user.cmp(&b.user),
+            SortColumn::RunTime => a.run_time.cmp(&b.run_time),
+        };
+        let ordering = if ascending { ordering } else { ordering.reverse() };
+        ordering.then(a.pid.cmp(&b.pid))
+    });
+}
+
+/// How the process table groups its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum GroupingMode {
+    None,
+    Name,
+    ExePath,
+    /// Indented parent/child tree rather than flat groups.
+    Tree,
+}
+
+/// Processes collapsed under one group header, with their CPU and memory summed.
+struct ProcessGroup<'a> {
+    key: String,
+    label: String,
+    members: Vec<&'a ProcessData>,
+    cpu_usage: f32,
+    memory: u64,
+}
+
+/// Running CPU/memory means for a process since it was first seen this session.
+#[derive(Debug, Clone, Copy, Default)]
+struct SessionAverage {
+    samples: u64,
+    cpu_usage: f64,
+    memory: f64,
+}
+
+impl SessionAverage {
+    fn record(&mut self, cpu_usage: f32, memory: u64) {
+        self.samples += 1;
+        let n = self.samples as f64;
+        self.cpu_usage += (cpu_usage as f64 - self.cpu_usage) / n;
+        self.memory += (memory as f64 - self.memory) / n;
+    }
+}
+
+/// Settings changes not yet written, so a burst of changes is saved once.
+#[derive(Debug, Clone, Copy, Default)]
+struct PendingSave {
+    /// Bumped on every change so only the newest pending flush writes.
+    generation: u64,
+    dirty: bool,
+}
+
+impl PendingSave {
+    /// Records a change and returns the generation its delayed flush should carry.
+    fn mark(&mut self) -> u64 {
+        self.dirty = true;
+        self.generation += 1;
+        self.generation
+    }
+
+    /// Whether the flush scheduled for `generation` should write; claims the change if so.
+    fn flush(&mut self, generation: u64) -> bool {
+        if generation != self.generation || !self.dirty {
+            return false;
+        }
+        self.dirty = false;
+        true
+    }
+
+    /// Claims any unwritten change without waiting out the debounce, as on quit.
+    fn take(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+}
+
+/// How long process table rebuilds take, and the refresh interval they currently allow.
+#[derive(Debug, Clone, Copy)]
+struct RebuildTiming {
+    interval: Duration,
+    last: Option<Duration>,
+    slowest: Option<Duration>,
+    /// Set once the user has been told about a back-off, so it isn't repeated every tick.
+    warned: bool,
+}
+
+impl Default for RebuildTiming {
+    fn default() -> Self {
+        Self { interval: PROCESS_REFRESH_INTERVAL, last: None, slowest: None, warned: false }
+    }
+}
+
+impl RebuildTiming {
+    fn record(&mut self, elapsed: Duration) {
+        self.last = Some(elapsed);
+        self.slowest = Some(self.slowest.map_or(elapsed, |slowest| slowest.max(elapsed)));
+    }
+
+    fn summary(&self) -> String {
+        let millis = |d: Option<Duration>| d.map_or("n/a".to_string(), |d| format!("{} ms", d.as_millis()));
+        format!(
+            "Process rebuild: last {}, slowest {}, refreshing every {}s",
+            millis(self.last),
+            millis(self.slowest),
+            self.interval.as_secs(),
+        )
+    }
+}
+
+/// The slower process refresh interval to switch to after a rebuild took `rebuild`, or
+/// `None` if the current interval still leaves enough headroom.
+fn backed_off_interval(interval: Duration, rebuild: Duration) -> Option<Duration> {
+    if rebuild.saturating_mul(SLOW_REBUILD_HEADROOM) <= interval || interval >= MAX_PROCESS_REFRESH_INTERVAL {
+        return None;
+    }
+    Some(interval.saturating_mul(2).min(MAX_PROCESS_REFRESH_INTERVAL))
+}
+
+/// Sustained-usage tracker for one system metric. It fires once when usage has stayed over
+/// the threshold for `ALERT_SUSTAIN_SAMPLES` samples, then stays quiet until usage drops back
+/// below the threshold and `ALERT_COOLDOWN` has passed.
+#[derive(Debug, Clone, Copy, Default)]
+struct UsageAlert {
+    samples_over: u32,
+    fired: bool,
+    last_fired: Option<Instant>,
+}
+
+impl UsageAlert {
+    /// Records one sample and returns whether the alert should fire now.
+    fn observe(&mut self, value: f32, threshold: f32, now: Instant) -> bool {
+        if value <= threshold {
+            self.samples_over = 0;
+            self.fired = false;
+            return false;
+        }
+        self.samples_over = self.samples_over.saturating_add(1);
+        let cooled_down = self.last_fired.is_none_or(|last| now.duration_since(last) >= ALERT_COOLDOWN);
+        if self.fired || self.samples_over < ALERT_SUSTAIN_SAMPLES || !cooled_down {
+            return false;
+        }
+        self.fired = true;
+        self.last_fired = Some(now);
+        true
+    }
+}
+
+/// One flattened row of the process tree.
+struct TreeRow<'a> {
+    process: &'a ProcessData,
+    depth: usize,
+    has_children: bool,
+    /// Descendants folded away because the row sits at the depth limit and isn't expanded.
+    hidden_descendants: usize,
+}
+
+/// State of a detached window plotting a single process over time.
+#[derive(Debug, Clone)]
+struct ProcessGraphWindow {
+    pid: Pid,
+    name: String,
+    cpu_history: VecDeque<f32>,
+    mem_history: VecDeque<f32>,
+    ended: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProcessExportData {
+    pid: usize,
+    name: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+}
+
+/// One process table row as written to a snapshot. Fields missing from older snapshots,
+/// which only had PID, name, CPU and memory, load as their defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SnapshotProcess {
+    pid: usize,
+    name: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+    swap_bytes: Option<u64>,
+    /// `ProcessStatus` as displayed, e.g. "Runnable".
+    status: String,
+    exe: Option<String>,
+    cmd: String,
+    user: Option<String>,
+    parent: Option<usize>,
+    disk_read_rate: f64,
+    disk_write_rate: f64,
+    run_time: Option<u64>,
+    start_time: u64,
+}
+
+impl SnapshotProcess {
+    fn capture(process: &ProcessData) -> Self {
+        Self {
+            pid: process.pid.as_u32() as usize,
+            name: process.name.clone(),
+            cpu_usage: process.cpu_usage,
+            memory_bytes: process.memory,
+            swap_bytes: process.swap,
+            status: process.status.to_string(),
+            exe: process.exe.clone(),
+            cmd: process.cmd.clone(),
+            user: process.user.clone(),
+            parent: process.parent.map(|pid| pid.as_u32() as usize),
+            disk_read_rate: process.disk_read_rate,
+            disk_write_rate: process.disk_write_rate,
+            run_time: process.run_time,
+            start_time: process.start_time,
+        }
+    }
+
+    /// Back into a table row for replay. Exited processes aren't saved, so none are marked.
+    fn restore(self) -> ProcessData {
+        ProcessData {
+            pid: Pid::from(self.pid),
+            name: self.name,
+            cpu_usage: self.cpu_usage,
+            memory: self.memory_bytes,
+            swap: self.swap_bytes,
+            status: status_from_label(&self.status),
+            exe: self.exe,
+            cmd: self.cmd,
+            user: self.user,
+            parent: self.parent.map(Pid::from),
+            exited_at: None,
+            disk_read_rate: self.disk_read_rate,
+            disk_write_rate: self.disk_write_rate,
+            run_time: self.run_time,
+            start_time: self.start_time,
+        }
+    }
+}
+
+/// Reverses `ProcessStatus`'s `Display`, which differs per platform, so it is matched
+/// against the labels this build produces. Anything else is `Unknown`.
+fn status_from_label(label: &str) -> ProcessStatus {
+    [
+        ProcessStatus::Run,
+        ProcessStatus::Sleep,
+        ProcessStatus::Idle,
+        ProcessStatus::Stop,
+        ProcessStatus::Zombie,
+        ProcessStatus::Tracing,
+        ProcessStatus::Dead,
+        ProcessStatus::Wakekill,
+        ProcessStatus::Waking,
+        ProcessStatus::Parked,
+        ProcessStatus::LockBlocked,
+        ProcessStatus::UninterruptibleDiskSleep,
+    ]
+    .into_iter()
+    .find(|status| status.to_string() == label)
+    .unwrap_or(ProcessStatus::Unknown(0))
+}
+
+/// Everything the app currently knows about the machine, written by "Save Snapshot" and
+/// read back by "Load Snapshot" for replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SystemSnapshot {
+    /// `APP_VERSION` when saved; whatever the file says when loaded.
+    app_version: std::borrow::Cow<'static, str>,
+    /// RFC 3339 local time the snapshot was taken.
+    timestamp: String,
+    system: SystemData,
+    processes: Vec<SnapshotProcess>,
+    networks: Vec<NetworkData>,
+    disks: Vec<DiskData>,
+}
+
+/// An entry in the process table's user filter. `All` carries the localized label, so a
+/// user whose name happens to match it is still a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum UserChoice {
+    All(&'static str),
+    User(String),
+}
+
+impl std::fmt::Display for UserChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserChoice::All(label) => f.write_str(label),
+            UserChoice::User(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Spacing and text sizes shared by the normal and compact layouts.
+#[derive(Debug, Clone, Copy)]
+struct Sizing {
+    page_padding: u16,
+    card_padding: u16,
+    card_gap: f32,
+    card_title_size: u16,
+    card_value_size: u16,
+    /// Vertical gap between dashboard sections.
+    section_gap: f32,
+    row_padding: u16,
+    /// Height reserved for the sparkline under a card value.
+    sparkline_height: f32,
+}
+
+impl Sizing {
+    const NORMAL: Sizing = Sizing {
+        page_padding: 40,
+        card_padding: 20,
+        card_gap: 10.0,
+        card_title_size: 18,
+        card_value_size: 36,
+        section_gap: 20.0,
+        row_padding: 5,
+        sparkline_height: 30.0,
+    };
+    const COMPACT: Sizing = Sizing {
+        page_padding: 12,
+        card_padding: 8,
+        card_gap: 4.0,
+        card_title_size: 14,
+        card_value_size: 22,
+        section_gap: 10.0,
+        row_padding: 1,
+        sparkline_height: 16.0,
+    };
+}
+
+/// Quiet period after the last settings change before settings are written to disk.
+const SETTINGS_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Consecutive metric samples over a threshold before a usage alert fires.
+const ALERT_SUSTAIN_SAMPLES: u32 = 5;
+/// Minimum time between two alerts for the same metric.
+const ALERT_COOLDOWN: Duration = Duration::from_secs(300);
+/// Alert thresholds offered by the settings sliders and accepted from a settings file, in percent.
+const ALERT_THRESHOLD_RANGE: std::ops::RangeInclusive<f32> = 10.0..=100.0;
+
+/// Signals offered in the detail pane's signal picker.
+const SIGNAL_CHOICES: [Signal; 5] = [Signal::Term, Signal::Kill, Signal::Hangup, Signal::Stop, Signal::Continue];
+
+/// Disks fuller than this get a red usage bar.
+const DISK_USAGE_WARNING_PERCENT: f32 = 90.0;
+
+/// Choices offered for how often global CPU/memory metrics are re-sampled.
+const REFRESH_INTERVAL_OPTIONS: [u64; 4] = [1, 2, 5, 10];
+/// How often the process table is rebuilt; kept separate so it can be slower than metrics.
+const PROCESS_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+/// Upper bound for the process refresh interval when backing off slow rebuilds.
+const MAX_PROCESS_REFRESH_INTERVAL: Duration = Duration::from_secs(16);
+/// A rebuild must fit this many times into the refresh interval, otherwise the interval
+/// is doubled so ticks don't pile up behind each other.
+const SLOW_REBUILD_HEADROOM: u32 = 2;
+
+/// How often the OS light/dark preference is re-read while the theme follows it.
+const SYSTEM_THEME_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How often tray icon clicks and menu picks are collected.
+const TRAY_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Ids of the tray menu entries, matched against incoming menu events.
+#[cfg(feature = "tray")]
+const TRAY_TOGGLE_ID: &str = "toggle-window";
+#[cfg(feature = "tray")]
+const TRAY_QUIT_ID: &str = "quit";
+
+/// Sampling period for pop-out process graphs, finer than the main refresh.
+const GRAPH_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+/// Samples kept per pop-out graph (one minute at `GRAPH_SAMPLE_INTERVAL`).
+const GRAPH_HISTORY_LEN: usize = 240;
+
+/// Samples kept for dashboard metric history (one minute at the default refresh).
+const HISTORY_LEN: usize = 60;
+/// Most recent samples drawn in a card sparkline.
+const SPARKLINE_SAMPLES: usize = 30;
+/// Samples needed before a card draws its sparkline at all.
+const SPARKLINE_MIN_SAMPLES: usize = 5;
+/// Samples used to judge a card's short-term trend.
+const TREND_WINDOW: usize = 10;
+/// Minimum slope, in percentage points per sample, before a card shows a rising/falling arrow.
+const TREND_THRESHOLD: f32 = 0.5;
+
+/// Interval choices for scheduled process list exports, in minutes.
+const AUTO_EXPORT_INTERVAL_OPTIONS: [u64; 4] = [1, 5, 10, 30];
+
+/// Retention choices for scheduled export files: maximum files kept, and maximum age in days.
+const EXPORT_RETENTION_FILES_OPTIONS: [usize; 4] = [100, 500, 1000, 5000];
+const EXPORT_RETENTION_DAYS_OPTIONS: [u64; 4] = [7, 30, 90, 365];
+/// Timestamp embedded in export, snapshot and screenshot file names, e.g.
+/// `processes_20240131_235959.csv`.
+const AUTO_EXPORT_TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// Grace period choices for rows of exited processes, in milliseconds.
+const PROCESS_GRACE_PERIOD_OPTIONS: [u64; 4] = [0, 1000, 2000, 5000];
+/// Weight of the newest sample in the smoothed process count shown on the dashboard.
+const PROCESS_COUNT_SMOOTHING: f64 = 0.3;
+/// Minimum time between full per-process swap scans for the dashboard's top swappers card.
+const SWAP_SCAN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Niceness change applied by each "Nice +/-" button in the detail pane.
+const NICE_STEP: i32 = 5;
+
+/// Pause between killing a process and starting it again on "Restart", so it has let go of
+/// ports and lock files by the time the new instance starts.
+const RESTART_DELAY: Duration = Duration::from_millis(500);
+
+/// Frame period while dashboard card values ease toward a new reading.
+const CARD_ANIMATION_FRAME: Duration = Duration::from_millis(16);
+/// Fraction of the remaining distance covered per frame (settles in roughly a quarter second).
+const CARD_EASING: f64 = 0.2;
+
+/// Host details included in the diagnostics report; `None` where the platform doesn't say.
+struct OsInfo {
+    name: Option<String>,
+    os_version: Option<String>,
+    kernel_version: Option<String>,
+    host_name: Option<String>,
+    cpu_brand: Option<String>,
+    /// Logical cores, i.e. `System::cpus().len()`.
+    core_count: usize,
+    /// `None` where the platform doesn't report physical cores.
+    physical_core_count: Option<usize>,
+}
+
+impl OsInfo {
+    fn collect(sys: &System) -> Self {
+        Self {
+            name: System::name(),
+            os_version: System::os_version(),
+            kernel_version: System::kernel_version(),
+            host_name: System::host_name(),
+            cpu_brand: sys.cpus().first().map(|cpu| cpu.brand().trim().to_string()).filter(|b| !b.is_empty()),
+            core_count: sys.cpus().len(),
+            physical_core_count: sys.physical_core_count(),
+        }
+    }
+
+    /// "8 physical / 16 logical", or just the logical count when physical is unknown.
+    fn core_summary(&self) -> String {
+        match self.physical_core_count {
+            Some(physical) => format!("{} physical / {} logical", physical, self.core_count),
+            None => format!("{} logical", self.core_count),
+        }
+    }
+}
+
+/// Lines kept in the process start/exit event log.
+const EVENT_LOG_CAPACITY: usize = 200;
+
+/// Process tab width below which the detail pane moves under the table.
+const NARROW_LAYOUT_WIDTH: f32 = 1000.0;
+
+/// Processes listed in the diagnostics report.
+const DIAGNOSTICS_TOP_PROCESSES: usize = 10;
+
+/// How long the metrics endpoint waits for a client to send its request line.
+const METRICS_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Latest readings served by the metrics endpoint, refreshed with the dashboard.
+#[derive(Debug, Clone, Default, Serialize)]
+struct MetricsSnapshot {
+    cpu_usage_percent: f32,
+    memory_used_bytes: u64,
+    memory_total_bytes: u64,
+    swap_used_bytes: u64,
+    swap_total_bytes: u64,
+    process_count: usize,
+}
+
+impl MetricsSnapshot {
+    /// Prometheus text exposition format.
+    fn to_prometheus(&self) -> String {
+        let gauges = [
+            ("system_cpu_usage_percent", "Global CPU usage in percent.", self.cpu_usage_percent as f64),
+            ("system_memory_used_bytes", "Memory in use.", self.memory_used_bytes as f64),
+            ("system_memory_total_bytes", "Total physical memory.", self.memory_total_bytes as f64),
+            ("system_swap_used_bytes", "Swap in use.", self.swap_used_bytes as f64),
+            ("system_swap_total_bytes", "Total swap.", self.swap_total_bytes as f64),
+            ("system_process_count", "Number of running processes.", self.process_count as f64),
+        ];
+        gauges.iter().map(|(name, help, value)| {
+            format!("# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n")
+        }).collect()
+    }
+}
+
+/// Bundled release notes shown once per new version.
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Users listed by name in the dashboard's CPU-by-user breakdown; the rest share one row.
+const CPU_BY_USER_TOP: usize = 5;
+/// Processes listed in the dashboard's top memory consumers card.
+const TOP_MEMORY_COUNT: usize = 5;
+
+/// Depth limit choices for the process tree view.
+const TREE_DEPTH_LIMIT_OPTIONS: [usize; 4] = [3, 6, 10, 20];
+
+/// Row limit choices offered in the settings tab for the process table.
+const PROCESS_ROW_LIMIT_OPTIONS: [usize; 4] = [100, 200, 500, 1000];
+
+/// Hover delay choices offered in the settings tab, in milliseconds.
+const TOOLTIP_DELAY_OPTIONS: [u64; 4] = [0, 250, 500, 1000];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TooltipField {
+    Name,
+    Exe,
+    Cmd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotificationLevel { Success, Error }
+
+#[derive(Debug, Clone)]
+struct StatusMessage { 
+    message: String, 
+    level: NotificationLevel,
+    /// Matches the message to the `ClearStatusMessage` timer scheduled for it; assigned by
+    /// `App::show_status`, and 0 for messages that stay until replaced.
+    id: u64,
+}
+
+impl StatusMessage {
+    fn success(message: &str) -> Self { 
+        Self { 
+            message: message.to_string(), 
+            level: NotificationLevel::Success,
+            id: 0,
+        } 
+    }
+    fn error(message: &str) -> Self { 
+        Self { 
             message: message.to_string(), 
-            level: NotificationLevel::Error 
+            level: NotificationLevel::Error,
+            id: 0,
         } 
     }
 }
 
+/// Captured at kill time so the process can be started again from the status bar.
+#[derive(Debug, Clone)]
+struct RelaunchCommand {
+    name: String,
+    program: PathBuf,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+}
+
+impl RelaunchCommand {
+    fn capture(process: &sysinfo::Process) -> Option<Self> {
+        let (first, args) = process.cmd().split_first()?;
+        let program = process.exe().map_or_else(|| PathBuf::from(first), |p| p.to_path_buf());
+        Some(Self {
+            name: process.name().to_string(),
+            program,
+            args: args.to_vec(),
+            cwd: process.cwd().map(|p| p.to_path_buf()),
+        })
+    }
+
+    fn spawn(&self) -> std::io::Result<u32> {
+        let mut command = std::process::Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        command.spawn().map(|child| {
+            let id = child.id();
+            reap_in_background(child);
+            id
+        })
+    }
+}
+
 struct App {
     system: System,
+    users: Users,
+    networks: Networks,
+    network_data: Vec<NetworkData>,
+    last_network_refresh: Instant,
+    /// Cumulative (read, written) disk bytes per PID at the last process refresh.
+    disk_io_totals: HashMap<Pid, (u64, u64)>,
+    last_process_refresh: Instant,
+    /// When `refresh_swap` last read swap for every process, for the dashboard.
+    last_swap_scan: Option<Instant>,
+    disks: Disks,
+    disk_data: Vec<DiskData>,
+    components: Components,
+    sensor_data: Vec<SensorData>,
+    /// NVML handle, `None` when the NVIDIA driver isn't available.
+    #[cfg(feature = "gpu")]
+    nvml: Option<nvml_wrapper::Nvml>,
+    gpu_data: Vec<GpuData>,
+    /// Ranked CPU totals per user, rebuilt with each process refresh.
+    cpu_by_user: Vec<(String, f32)>,
+    /// Largest processes by resident memory, for the dashboard's top consumers card.
+    top_memory: Vec<(Pid, String, u64)>,
+    /// Live processes per `ProcessStatus` label, rebuilt with each process refresh.
+    status_counts: HashMap<String, usize>,
+    /// Sorted owners of the listed processes, for the user filter and the User column.
+    process_users: Vec<String>,
+    /// Host details for the dashboard's uptime card; they don't change while the app runs.
+    os_info: OsInfo,
     active_tab: Tab,
     dashboard_data: SystemData,
     process_list: Vec<ProcessData>,
     selected_process: Option<Pid>,
+    /// Name of the selected process when it was picked, so a recycled PID isn't mistaken for it.
+    selected_process_name: Option<String>,
+    selection_baseline: Option<SelectionBaseline>,
+    /// Open file descriptor count of the selected process, re-read on each process refresh
+    /// rather than on every redraw.
+    selected_fd_count: Option<(Pid, Option<usize>)>,
+    /// Niceness of the selected process, cached the same way as `selected_fd_count`.
+    selected_nice: Option<(Pid, Option<i32>)>,
     show_kill_confirm: Option<Pid>,
+    /// Group label and member PIDs of a pending "kill all" from a group header.
+    show_bulk_kill_confirm: Option<(String, Vec<Pid>)>,
+    show_about: bool,
+    /// Row whose right-click menu is open, shown just under that row.
+    context_menu: Option<Pid>,
+    /// Signal picked in the detail pane for "Send".
+    selected_signal: Signal,
+    show_signal_confirm: Option<(Pid, Signal)>,
+    show_restart_confirm: Option<Pid>,
     last_status_message: Option<StatusMessage>,
+    /// Id given to the last message shown through `show_status`.
+    last_status_id: u64,
+    relaunch_offer: Option<RelaunchCommand>,
     settings: AppSettings,
     is_loading: bool,
+    hovered_process: Option<Pid>,
+    tooltip_visible: bool,
+    read_only_forced: bool,
+    pid_jump_input: String,
+    /// Case-insensitive name filter for the process table; empty shows everything.
+    process_filter: String,
+    /// Also match the filter against each process's command line.
+    filter_command_line: bool,
+    /// Only list processes owned by this user; `None` shows every user's.
+    user_filter: Option<String>,
+    /// Set while a loaded snapshot is shown instead of live data; refreshes and
+    /// destructive actions are off until the user exits replay.
+    is_replaying: bool,
+    /// "Replaying <file> from <time>" text for the replay banner.
+    replay_info: String,
+    /// Path typed next to "Load Snapshot"; empty loads the newest saved snapshot.
+    snapshot_path_input: String,
+    /// Text of the minimum CPU % and resident memory (MiB) inputs as typed, parsed when
+    /// filtering; empty or unparsable text disables each.
+    min_cpu_input: String,
+    min_mem_input: String,
+    sort_column: SortColumn,
+    sort_ascending: bool,
+    graph_windows: HashMap<window::Id, ProcessGraphWindow>,
+    smoothed_process_count: f64,
+    /// Values currently shown on the CPU and memory cards; they ease toward `dashboard_data`.
+    displayed_cpu_usage: f64,
+    displayed_memory_used: f64,
+    show_auto_rules_confirm: bool,
+    rule_draft: RuleDraft,
+    /// Keyed by (index into `settings.auto_rules`, PID).
+    rule_breaches: HashMap<(usize, Pid), RuleBreach>,
+    cpu_alert: UsageAlert,
+    mem_alert: UsageAlert,
+    pending_save: PendingSave,
+    /// Freezes the displayed data: scheduled refreshes are skipped until resumed.
+    is_paused: bool,
+    cpu_history: VecDeque<f32>,
+    /// Memory usage in percent of total.
+    mem_history: VecDeque<f32>,
+    /// Swap usage in percent of total.
+    swap_history: VecDeque<f32>,
+    /// One usage history per logical core, resized if the core count changes.
+    per_core_history: Vec<VecDeque<f32>>,
+    last_auto_export: Option<chrono::DateTime<chrono::Local>>,
+    /// Pinned process shown side-by-side with `selected_process`.
+    compare_process: Option<Pid>,
+    expanded_groups: std::collections::HashSet<String>,
+    /// Tree nodes the user expanded past the depth limit.
+    expanded_tree_nodes: std::collections::HashSet<Pid>,
+    /// PIDs alive at the last process refresh, diffed against the next one for the event log.
+    previous_pids: std::collections::HashSet<Pid>,
+    /// Timestamped "Started"/"Exited" lines, oldest first, capped at `EVENT_LOG_CAPACITY`.
+    event_log: VecDeque<String>,
+    show_event_log: bool,
+    /// Last minimized state reported for the main window.
+    minimized: bool,
+    /// OS dark mode preference, read when the theme is `System`.
+    system_dark: bool,
+    /// Keeps the tray icon alive, `None` when it couldn't be created.
+    #[cfg(feature = "tray")]
+    tray: Option<Tray>,
+    /// Whether the main window is currently hidden to the tray.
+    window_hidden: bool,
+    /// Per-PID averages since first seen, dropped once the PID exits.
+    session_averages: HashMap<Pid, SessionAverage>,
+    rebuild_timing: RebuildTiming,
+    metrics_snapshot: std::sync::Arc<std::sync::RwLock<MetricsSnapshot>>,
+    /// Port of the running metrics server and the handle whose drop shuts it down.
+    metrics_server: Option<(u16, tokio::sync::watch::Sender<()>)>,
+    metrics_port_input: String,
+    profile_name_input: String,
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 enum Message {
-    Tick,
-    SettingsLoaded(Result<AppSettings, String>),
+    RefreshMetrics,
+    RefreshProcesses,
+    SettingsLoaded(Result<(AppSettings, Option<PathBuf>), String>),
     SettingsSaved(Result<(), String>),
     ThemeChanged(ThemeChoice),
+    SystemThemePoll,
+    SystemThemeDetected(bool),
+    LanguageChanged(Language),
+    AccentToggled(bool),
+    AccentChannelChanged(usize, f32),
     TabSelected(Tab),
     ProcessSelected(Pid),
     KillProcessRequested(Pid),
     KillProcessConfirmed(Pid),
+    KillGroupRequested(String, Vec<Pid>),
+    KillGroupConfirmed,
     KillProcessCancelled,
-    ClearStatusMessage,
+    ShowAbout,
+    HideAbout,
+    KillSelectedRequested,
+    TogglePause,
+    UsageAlertsToggled(bool),
+    CpuAlertThresholdChanged(f32),
+    MemAlertThresholdChanged(f32),
+    AlertThresholdReleased,
+    KeyboardShortcut(Box<Message>),
+    SignalSelected(Signal),
+    SendSignal(Pid, Signal),
+    SignalConfirmed(Pid, Signal),
+    RestartProcess(Pid),
+    RestartConfirmed(Pid),
+    /// Starts the new instance once `RESTART_DELAY` has passed after the kill.
+    RestartRelaunch(RelaunchCommand),
+    ClearStatusMessage(u64),
     ExportCsvRequested,
+    ExportSnapshot,
+    SnapshotSaved(Result<String, String>),
+    SnapshotPathChanged(String),
+    LoadSnapshot,
+    SnapshotLoaded(Result<(String, Box<SystemSnapshot>), String>),
+    ExitReplay,
     ExportCsvSaved(Result<String, String>),
+    CopySettingsRequested,
+    PasteSettingsRequested,
+    SettingsPasted(Option<String>),
+    SettingsBackedUp(Result<(), String>, AppSettings),
+    ProcessHovered(Pid),
+    ProcessHoverEnded(Pid),
+    ProcessContextMenu(Pid),
+    ContextMenuDismissed,
+    CopyToClipboard(String),
+    TooltipDelayElapsed(Pid),
+    TooltipDelayChanged(u64),
+    TooltipFieldToggled(TooltipField, bool),
+    WindowResized(WindowSize),
+    FlushSettings(u64),
+    RememberWindowSizeToggled(bool),
+    LiveWindowTitleToggled(bool),
+    DeveloperToolsToggled(bool),
+    ReduceMotionToggled(bool),
+    GraphsToggled(bool),
+    StartInTrayToggled(bool),
+    /// The main window's close button was pressed.
+    CloseRequested,
+    /// Collects tray icon clicks and menu picks.
+    TrayPoll,
+    BackgroundSamplingToggled(bool),
+    MinimizedChecked(Option<bool>),
+    ChangelogDismissed,
+    ProfileSelected(String),
+    ProfileNameInputChanged(String),
+    SaveProfileRequested,
+    RenameProfileRequested,
+    DeleteProfileRequested,
+    MetricsEndpointToggled(bool),
+    MetricsPortInputChanged(String),
+    MetricsPortSubmitted,
+    /// A metrics server on the given port exited, with why if it failed.
+    MetricsServerStopped(u16, Result<(), String>),
+    AnimationFrame,
+    DebuggerCommandChanged(String),
+    DebuggerCommandSubmitted,
+    OpenInDebugger(Pid),
+    ProcessRowLimitChanged(usize),
+    ReadOnlyToggled(bool),
+    PidJumpInputChanged(String),
+    ProcessFilterChanged(String),
+    FilterCommandLineToggled(bool),
+    UserFilterChanged(Option<String>),
+    EventLogToggled,
+    MinCpuFilterChanged(String),
+    MinMemFilterChanged(String),
+    SortBy(SortColumn),
+    PidJumpSubmitted,
+    JumpToProcess(Pid),
+    NumberGroupingChanged(NumberGrouping),
+    KillConfirmModeChanged(KillConfirmMode),
+    PopOutGraph(Pid),
+    SampleGraphs,
+    WindowClosed(window::Id),
+    ProcessGracePeriodChanged(u64),
+    CopyVisiblePids,
+    ColumnVisibilityToggled(SortColumn, bool),
+    AutoRulesEnableRequested(bool),
+    AutoRulesEnableConfirmed,
+    AutoRulesEnableCancelled,
+    RuleDraftChanged(RuleDraftField),
+    AddAutoRule,
+    RemoveAutoRule(usize),
+    ScreenshotRequested,
+    ScreenshotCaptured(window::Screenshot),
+    ScreenshotSaved(Result<String, String>),
+    ScreenshotDirChanged(String),
+    ScreenshotDirSubmitted,
+    AutoExportTick,
+    AutoExportSaved(Result<String, String>),
+    ExportsPruned(Result<usize, String>),
+    ExportRetentionFilesChanged(usize),
+    RefreshIntervalChanged(u64),
+    ExportRetentionDaysChanged(u64),
+    AutoExportToggled(bool),
+    AutoExportIntervalChanged(u64),
+    AutoExportFormatChanged(ExportFormat),
+    AutoExportDirChanged(String),
+    AutoExportDirSubmitted,
+    /// Edits to read-only selectable fields; ignored so the value never changes.
+    SelectableTextEdited,
+    CompareProcessPinned(Pid),
+    CompareCleared,
+    GroupingModeChanged(GroupingMode),
+    GroupToggled(String),
+    TreeNodeToggled(Pid),
+    TreeDepthLimitChanged(usize),
+    MemoryBarsToggled(bool),
+    CompactModeToggled(bool),
+    ZebraStripingToggled(bool),
+    RowGridlinesToggled(bool),
+    MemoryMetricChanged(MemoryMetric),
+    MemoryUnitChanged(MemoryUnit),
+    CpuCapToggled(bool),
+    CopyProcessDetails(Pid),
+    CopySelectedDetails,
+    CopyDiagnostics,
+    ManualRefresh,
+    RelaunchRequested,
+    SuspendToggled(Pid),
+    AdjustPriority(Pid, i32),
+    OpenLocationRequested(Pid),
+    LaunchAnotherInstance(Pid),
+    DetailActionToggled(DetailAction, bool),
+    DetailActionMoved(DetailAction, bool),
 }
 
 impl Application for App {
     type Executor = executor::Default;
     type Message = Message;
     type Theme = Theme;
-    type Flags = ();
+    type Flags = StartupFlags;
 
-    fn new(_flags: ()) -> (Self, Command<Message>) {
+    fn new(flags: StartupFlags) -> (Self, Command<Message>) {
         let mut sys = System::new_all();
         sys.refresh_all();
 
-        let to_gb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-
+        let (memory_used, memory_metric) = MemoryMetric::Used.read(&sys);
         let dashboard_data = SystemData {
             cpu_usage: sys.global_cpu_info().cpu_usage(),
-            memory_used: to_gb(sys.used_memory()),
-            memory_total: to_gb(sys.total_memory()),
+            memory_used,
+            memory_metric,
+            memory_other: memory_metric.read_other(&sys),
+            memory_total: sys.total_memory(),
+            swap_used: sys.used_swap(),
+            swap_total: sys.total_swap(),
+            per_core_usage: sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+            per_core_frequency: sys.cpus().iter().map(|cpu| cpu.frequency()).collect(),
+            max_frequency: read_max_cpu_frequency(),
+            uptime_secs: System::uptime(),
+            boot_time: System::boot_time(),
+            load_average: read_load_average(),
             process_count: sys.processes().len(),
         };
 
-        let process_list = App::build_process_list(&sys);
+        let os_info = OsInfo::collect(&sys);
+        let users = Users::new_with_refreshed_list();
+        let components = Components::new_with_refreshed_list();
+        let cpu_cap = Some(sys.cpus().len().max(1) as f32 * 100.0);
+        let (process_list, _) = App::build_process_list(Vec::new(), &sys, &users, cpu_cap);
+        let previous_pids = process_list.iter().map(|p| p.pid).collect();
+        let disk_io_totals = disk_io_totals(&sys);
+        let status_counts = count_by_status(&process_list);
+        let process_users = distinct_users(&process_list);
+        let cpu_by_user = aggregate_cpu_by_user(&process_list, CPU_BY_USER_TOP);
+        let top_memory = top_by_memory(&process_list, TOP_MEMORY_COUNT);
+        let smoothed_process_count = dashboard_data.process_count as f64;
+        let displayed_cpu_usage = dashboard_data.cpu_usage as f64;
+        let displayed_memory_used = dashboard_data.memory_used as f64;
 
         (
             Self {
                 system: sys,
+                users,
+                networks: Networks::new_with_refreshed_list(),
+                network_data: Vec::new(),
+                last_network_refresh: Instant::now(),
+                disk_io_totals,
+                last_process_refresh: Instant::now(),
+                last_swap_scan: None,
+                disks: Disks::new(),
+                disk_data: Vec::new(),
+                sensor_data: SensorData::read_all(&components),
+                components,
+                #[cfg(feature = "gpu")]
+                nvml: nvml_wrapper::Nvml::init()
+                    .map_err(|e| tracing::info!("GPU metrics unavailable: {}", e))
+                    .ok(),
+                gpu_data: Vec::new(),
+                cpu_by_user,
+                top_memory,
                 active_tab: Tab::Dashboard,
                 dashboard_data,
                 process_list,
+                status_counts,
+                process_users,
+                os_info,
                 selected_process: None,
+                selected_process_name: None,
+                selection_baseline: None,
+                selected_fd_count: None,
+                selected_nice: None,
                 show_kill_confirm: None,
+                show_bulk_kill_confirm: None,
+                show_about: false,
+                context_menu: None,
+                selected_signal: Signal::Term,
+                show_signal_confirm: None,
+                show_restart_confirm: None,
                 last_status_message: None,
+                last_status_id: 0,
+                relaunch_offer: None,
                 settings: AppSettings::default(),
                 is_loading: true,
+                hovered_process: None,
+                tooltip_visible: false,
+                read_only_forced: flags.read_only,
+                pid_jump_input: String::new(),
+                process_filter: String::new(),
+                filter_command_line: false,
+                user_filter: None,
+                is_replaying: false,
+                replay_info: String::new(),
+                snapshot_path_input: String::new(),
+                min_cpu_input: String::new(),
+                min_mem_input: String::new(),
+                sort_column: SortColumn::Cpu,
+                sort_ascending: false,
+                graph_windows: HashMap::new(),
+                smoothed_process_count,
+                displayed_cpu_usage,
+                displayed_memory_used,
+                show_auto_rules_confirm: false,
+                rule_draft: RuleDraft::default(),
+                rule_breaches: HashMap::new(),
+                cpu_alert: UsageAlert::default(),
+                mem_alert: UsageAlert::default(),
+                is_paused: false,
+                pending_save: PendingSave::default(),
+                cpu_history: VecDeque::with_capacity(HISTORY_LEN),
+                mem_history: VecDeque::with_capacity(HISTORY_LEN),
+                swap_history: VecDeque::with_capacity(HISTORY_LEN),
+                per_core_history: Vec::new(),
+                last_auto_export: None,
+                compare_process: None,
+                expanded_groups: std::collections::HashSet::new(),
+                expanded_tree_nodes: std::collections::HashSet::new(),
+                previous_pids,
+                event_log: VecDeque::new(),
+                show_event_log: false,
+                session_averages: HashMap::new(),
+                rebuild_timing: RebuildTiming::default(),
+                minimized: false,
+                // Matches the detection fallback until the first poll after settings load.
+                system_dark: true,
+                #[cfg(feature = "tray")]
+                tray: start_tray()
+                    .map_err(|e| tracing::info!("Tray icon unavailable: {}", e))
+                    .ok(),
+                window_hidden: flags.start_in_tray,
+                metrics_snapshot: Default::default(),
+                metrics_server: None,
+                metrics_port_input: String::new(),
+                profile_name_input: String::new(),
             },
             Command::perform(AppSettings::load(), Message::SettingsLoaded),
         )
     }
 
-    fn title(&self) -> String {
-        String::from("System Monitor")
+    fn title(&self, window: window::Id) -> String {
+        match self.graph_windows.get(&window) {
+            Some(graph) => format!("{} (PID: {}) — System Monitor", graph.name, graph.pid),
+            None if self.settings.live_window_title => {
+                let data = &self.dashboard_data;
+                format!("System Monitor — CPU {:.0}% | Mem {:.0}%", data.cpu_usage, data.memory_percent())
+            }
+            None => String::from("System Monitor"),
+        }
     }
 
-    fn theme(&self) -> Theme {
-        self.settings.theme.to_theme()
+    fn theme(&self, _window: window::Id) -> Theme {
+        self.effective_theme().to_theme(self.settings.accent)
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        iced::time::every(Duration::from_secs(1)).map(|_| Message::Tick)
+        let mut subscriptions = vec![
+            iced::time::every(self.metrics_interval()).map(|_| Message::RefreshMetrics),
+            iced::time::every(self.process_interval()).map(|_| Message::RefreshProcesses),
+            event::listen_with(|event, status| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                    let command = modifiers.command();
+                    let shortcut = match key.as_ref() {
+                        // Escape always gets through so it can dismiss a confirmation.
+                        keyboard::Key::Named(keyboard::key::Named::Escape) => Some(Message::KillProcessCancelled),
+                        // Other shortcuts only fire when no widget (e.g. a focused text input)
+                        // took the key, so copy/paste and Delete inside text fields keep working.
+                        _ if status == event::Status::Captured => None,
+                        keyboard::Key::Character("e") if command => Some(Message::ExportCsvRequested),
+                        keyboard::Key::Character("c") if command => Some(Message::CopySelectedDetails),
+                        keyboard::Key::Character("r") if command => Some(Message::ManualRefresh),
+                        keyboard::Key::Named(keyboard::key::Named::F5) => Some(Message::ManualRefresh),
+                        keyboard::Key::Character(digit) if command => digit
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|n| n.checked_sub(1))
+                            .and_then(|index| Tab::ALL.get(index).copied())
+                            .map(Message::TabSelected),
+                        keyboard::Key::Named(keyboard::key::Named::Delete) if modifiers.is_empty() => {
+                            Some(Message::KillSelectedRequested)
+                        }
+                        _ => None,
+                    };
+                    shortcut.map(|message| Message::KeyboardShortcut(Box::new(message)))
+                }
+                Event::Window(id, window::Event::Resized { width, height }) if id == window::Id::MAIN => {
+                    Some(Message::WindowResized(WindowSize {
+                        width: width as f32,
+                        height: height as f32,
+                    }))
+                }
+                // Coming back to the window is a good moment to notice an OS theme switch.
+                Event::Window(id, window::Event::Focused) if id == window::Id::MAIN => Some(Message::SystemThemePoll),
+                Event::Window(id, window::Event::CloseRequested) if id == window::Id::MAIN => {
+                    Some(Message::CloseRequested)
+                }
+                Event::Window(id, window::Event::Closed) => Some(Message::WindowClosed(id)),
+                // Any left click closes a row's context menu. Waiting for the release lets a
+                // menu entry finish its own click first; entries carry their PID, so the order
+                // of the two messages doesn't matter.
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => Some(Message::ContextMenuDismissed),
+                _ => None,
+            }),
+        ];
+        if self.settings.auto_export_enabled {
+            let interval = Duration::from_secs(self.settings.auto_export_interval_mins * 60);
+            subscriptions.push(iced::time::every(interval).map(|_| Message::AutoExportTick));
+        }
+        if self.card_values_animating()
+            && !self.settings.reduce_motion
+            && self.active_tab == Tab::Dashboard
+            && !self.is_background_sampling()
+        {
+            subscriptions.push(iced::time::every(CARD_ANIMATION_FRAME).map(|_| Message::AnimationFrame));
+        }
+        if self.has_tray() {
+            subscriptions.push(iced::time::every(TRAY_POLL_INTERVAL).map(|_| Message::TrayPoll));
+        }
+        if self.settings.theme == ThemeChoice::System {
+            subscriptions.push(iced::time::every(SYSTEM_THEME_POLL_INTERVAL).map(|_| Message::SystemThemePoll));
+        }
+        // Extra sampling only runs while at least one graph window is still live, and not
+        // while paused so the graphs freeze along with everything else.
+        if !self.is_paused && self.graph_windows.values().any(|graph| !graph.ended) {
+            subscriptions.push(iced::time::every(GRAPH_SAMPLE_INTERVAL).map(|_| Message::SampleGraphs));
+        }
+        Subscription::batch(subscriptions)
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
-            Message::SettingsLoaded(Ok(settings)) => {
+            Message::SettingsLoaded(Ok((settings, backup))) => {
                 self.settings = settings;
                 self.is_loading = false;
-                tracing::info!("Settings loaded successfully");
-                Command::none()
+                self.metrics_port_input = self.settings.metrics_port.to_string();
+                self.active_tab = self.settings.last_active_tab;
+                if self.active_tab == Tab::Disks {
+                    self.refresh_disks();
+                }
+                let recovered = match backup {
+                    Some(backup) => {
+                        let err_msg = format!(
+                            "Settings file was corrupt and has been reset to defaults; the old file is at {} ⚠️",
+                            backup.display()
+                        );
+                        self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(8))
+                    }
+                    None => {
+                        tracing::info!("Settings loaded successfully");
+                        Command::none()
+                    }
+                };
+                Command::batch([
+                    self.prune_exports_command(),
+                    self.sync_metrics_server(),
+                    self.show_if_stranded(),
+                    self.update(Message::SystemThemePoll),
+                    recovered,
+                ])
             }
             Message::SettingsLoaded(Err(e)) => {
                 self.is_loading = false;
                 tracing::error!("Failed to load settings: {}", e);
                 self.last_status_message = Some(StatusMessage::error("Failed to load settings"));
-                Command::none()
+                self.show_if_stranded()
             }
             Message::ThemeChanged(theme_choice) => {
                 self.settings.theme = theme_choice;
                 tracing::info!("Theme changed, saving settings...");
-                Command::perform(self.settings.clone().save(), Message::SettingsSaved)
+                let save = self.schedule_save();
+                let detect = if theme_choice == ThemeChoice::System {
+                    self.update(Message::SystemThemePoll)
+                } else {
+                    Command::none()
+                };
+                Command::batch([save, detect])
+            }
+            Message::SystemThemePoll if self.settings.theme != ThemeChoice::System => Command::none(),
+            Message::SystemThemePoll => Command::perform(
+                // Detection may ask the desktop over D-Bus, so keep it off the UI thread.
+                async { tokio::task::spawn_blocking(system_prefers_dark).await.unwrap_or(true) },
+                Message::SystemThemeDetected,
+            ),
+            Message::SystemThemeDetected(dark) => {
+                self.system_dark = dark;
+                Command::none()
+            }
+            Message::LanguageChanged(language) => {
+                self.settings.language = language;
+                self.schedule_save()
+            }
+            Message::AccentToggled(enabled) => {
+                self.settings.accent = enabled.then(|| {
+                    let primary = self.effective_theme().to_theme(None).palette().primary;
+                    [primary.r, primary.g, primary.b]
+                });
+                self.schedule_save()
+            }
+            Message::AccentChannelChanged(channel, value) => {
+                if let Some(accent) = self.settings.accent.as_mut() {
+                    accent[channel] = value;
+                }
+                self.schedule_save()
             }
             Message::SettingsSaved(Ok(())) => {
                 tracing::info!("Settings saved successfully.");
-                self.last_status_message = Some(StatusMessage::success("Settings saved ✅"));
-                Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage)
+                self.show_status(StatusMessage::success("Settings saved ✅"), Duration::from_secs(3))
             }
             Message::SettingsSaved(Err(e)) => {
                 tracing::error!("Failed to save settings: {}", e);
-                self.last_status_message = Some(StatusMessage::error("Failed to save settings ⚠️"));
-                Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage)
+                self.show_status(StatusMessage::error("Failed to save settings ⚠️"), Duration::from_secs(3))
             }
             
-            Message::Tick => {
-                self.system.refresh_all(); 
-                let to_gb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-                self.dashboard_data = SystemData {
-                    cpu_usage: self.system.global_cpu_info().cpu_usage(),
-                    memory_used: to_gb(self.system.used_memory()),
-                    memory_total: to_gb(self.system.total_memory()),
-                    process_count: self.system.processes().len(),
-                };
-                self.process_list = App::build_process_list(&self.system);
-                if let Some(pid) = self.selected_process {
-                    if !self.system.processes().contains_key(&pid) {
-                        self.selected_process = None;
-                    }
-                }
+            Message::RefreshMetrics | Message::RefreshProcesses if self.is_paused => Command::none(),
+            // Historical data never changes; "Exit Replay" brings back live updates.
+            Message::RefreshMetrics | Message::RefreshProcesses | Message::ManualRefresh if self.is_replaying => {
                 Command::none()
             }
-            Message::TabSelected(tab) => {
-                self.active_tab = tab;
+            Message::RefreshMetrics => self.metrics_tick(),
+            // Automatic rules still need fresh process data while minimized.
+            Message::RefreshProcesses if self.is_background_sampling() && !self.settings.auto_rules_enabled => {
                 Command::none()
             }
-            Message::ProcessSelected(pid) => {
-                self.selected_process = Some(pid);
-                Command::none()
+            Message::RefreshProcesses => self.processes_tick(),
+            Message::EventLogToggled => {
+                self.show_event_log = !self.show_event_log;
+                if self.show_event_log {
+                    scrollable::snap_to(event_log_id(), scrollable::RelativeOffset::END)
+                } else {
+                    Command::none()
+                }
             }
-            Message::KillProcessRequested(pid) => {
-                self.show_kill_confirm = Some(pid);
+            Message::MinimizedChecked(minimized) => {
+                let minimized = minimized.unwrap_or(false);
+                let restored = self.minimized && !minimized;
+                self.minimized = minimized;
+                if restored {
+                    // Catch up on everything skipped while in the background.
+                    self.refresh_processes();
+                    self.snap_card_values();
+                    return self.evaluate_auto_rules();
+                }
                 Command::none()
             }
-            Message::KillProcessCancelled => {
-                self.show_kill_confirm = None;
-                Command::none()
+            Message::BackgroundSamplingToggled(enabled) => {
+                self.settings.background_sampling = enabled;
+                if !enabled && self.minimized {
+                    self.minimized = false;
+                    self.refresh_processes();
+                }
+                self.schedule_save()
             }
-            Message::KillProcessConfirmed(pid) => {
-                self.show_kill_confirm = None; 
-                let (status_message, command) = if let Some(process) = self.system.process(pid) {
-                    if process.kill() {
-                        let msg = StatusMessage::success(&format!("Process {} killed successfully ✅", pid));
-                        let cmd = Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage);
-                        (msg, cmd)
-                    } else {
-                        let err_msg = format!("Failed to kill process {} ⚠️ (Permission denied?)", pid);
-                        let msg = StatusMessage::error(&err_msg);
-                        let cmd = Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage);
-                        (msg, cmd)
+            // Runs even while paused: the user asked for this one update.
+            Message::ManualRefresh => {
+                let refreshed = self.refresh();
+                Command::batch([
+                    refreshed,
+                    self.show_status(StatusMessage::success("Refreshed ✅"), Duration::from_secs(1)),
+                ])
+            }
+            Message::CopySelectedDetails => match self.selected_process {
+                Some(pid) => self.update(Message::CopyProcessDetails(pid)),
+                None => Command::none(),
+            },
+            Message::CopyProcessDetails(pid) => {
+                let Some(details) = self.process_details(pid) else {
+                    return self.show_status(StatusMessage::error("Process is gone, nothing copied ⚠️"), Duration::from_secs(3));
+                };
+                Command::batch([
+                    iced::clipboard::write(details),
+                    self.show_status(StatusMessage::success("Copied process details to clipboard ✅"), Duration::from_secs(3)),
+                ])
+            }
+            Message::SuspendToggled(_) | Message::AdjustPriority(..) if self.is_read_only() => {
+                self.read_only_blocked()
+            }
+            Message::SuspendToggled(pid) => {
+                let label = self.process_label(pid);
+                let resume = self.system.process(pid).is_some_and(|p| p.status() == ProcessStatus::Stop);
+                let verb = if resume { "resume" } else { "suspend" };
+                let status = match suspend_process(pid, !resume) {
+                    Ok(()) => StatusMessage::success(&format!("Sent {} to {} ✅", verb, label)),
+                    Err(reason) => {
+                        tracing::error!("Failed to {} process {}: {}", verb, pid, reason);
+                        StatusMessage::error(&format!("Failed to {} {} ⚠️ ({})", verb, label, reason))
+                    }
+                };
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::AdjustPriority(pid, delta) => {
+                let label = self.process_label(pid);
+                let result = adjust_process_nice(pid, delta);
+                if let Ok(nice) = result {
+                    self.selected_nice = Some((pid, Some(nice)));
+                }
+                let status = match result {
+                    Ok(nice) => StatusMessage::success(&format!("Set {} to nice {} ✅", label, nice)),
+                    Err(reason) => {
+                        tracing::error!("Failed to renice process {}: {}", pid, reason);
+                        StatusMessage::error(&format!("Failed to change priority of {} ⚠️ ({})", label, reason))
                     }
-                } else {
-                    let err_msg = format!("Tried to kill non-existent process {}", pid);
-                    (
-                        StatusMessage::error(&err_msg),
-                        Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage)
-                    )
                 };
-                self.last_status_message = Some(status_message);
-                command
+                self.show_status(status, Duration::from_secs(3))
             }
-            Message::ClearStatusMessage => {
-                self.last_status_message = None;
+            Message::OpenLocationRequested(pid) => {
+                let exe = match self.replayed_row(pid) {
+                    Some(row) => row.exe.as_ref().map(PathBuf::from),
+                    None => self.system.process(pid).and_then(|p| p.exe()).map(|exe| exe.to_path_buf()),
+                };
+                let folder = exe.as_deref().and_then(|exe| exe.parent()).map(|dir| dir.to_path_buf());
+                let result = match folder {
+                    Some(folder) => open_folder(&folder),
+                    None => Err("executable path unknown".to_string()),
+                };
+                if let Err(reason) = result {
+                    let err_msg = format!("Failed to open location of {} ⚠️ ({})", self.process_label(pid), reason);
+                    return self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3));
+                }
                 Command::none()
             }
-            Message::ExportCsvRequested => {
-                let process_list: Vec<ProcessExportData> = self.process_list.iter().map(|p| {
-                    ProcessExportData {
-                        pid: p.pid.as_u32() as usize,
-                        name: p.name.clone(),
-                        cpu_usage: p.cpu_usage,
-                        memory_bytes: p.memory,
-                    }
-                }).collect();
-                Command::perform(App::save_csv(process_list), Message::ExportCsvSaved)
+            Message::LaunchAnotherInstance(_) | Message::RelaunchRequested if self.is_read_only() => {
+                self.relaunch_offer = None;
+                self.read_only_blocked()
             }
-            Message::ExportCsvSaved(Ok(path_str)) => {
-                tracing::info!("CSV saved successfully to: {}", path_str);
-                let success_msg = format!("CSV exported to {} ✅", path_str);
-                self.last_status_message = Some(StatusMessage::success(&success_msg));
-                Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage)
+            Message::LaunchAnotherInstance(pid) => {
+                self.relaunch_offer = self.system.process(pid).and_then(RelaunchCommand::capture);
+                if self.relaunch_offer.is_none() {
+                    let err_msg = format!("No command line captured for {} ⚠️", self.process_label(pid));
+                    return self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3));
+                }
+                self.update(Message::RelaunchRequested)
             }
-            Message::ExportCsvSaved(Err(e)) => {
-                tracing::error!("Failed to save CSV: {}", e);
-                self.last_status_message = Some(StatusMessage::error("Failed to export CSV ⚠️"));
-                Command::perform(tokio::time::sleep(Duration::from_secs(3)), |_| Message::ClearStatusMessage)
+            Message::DetailActionToggled(action, enabled) => {
+                self.settings.detail_actions.retain(|a| *a != action);
+                if enabled || action == DetailAction::Kill {
+                    self.settings.detail_actions.push(action);
+                }
+                self.schedule_save()
             }
-        }
-    }
-
-    fn view(&self) -> Element<'_, Message, Theme, Renderer> {
-        if self.is_loading {
-            let content = text("Loading settings...")
+            Message::DetailActionMoved(action, up) => {
+                let actions = &mut self.settings.detail_actions;
+                if let Some(index) = actions.iter().position(|a| *a == action) {
+                    let target = if up { index.checked_sub(1) } else { Some(index + 1) };
+                    if let Some(target) = target.filter(|t| *t < actions.len()) {
+                        actions.swap(index, target);
+                    }
+                }
+                self.schedule_save()
+            }
+            Message::TabSelected(tab) => {
+                self.active_tab = tab;
+                if tab == Tab::Disks {
+                    self.refresh_disks();
+                }
+                let save = if self.settings.last_active_tab != tab {
+                    self.settings.last_active_tab = tab;
+                    self.schedule_save()
+                } else {
+                    Command::none()
+                };
+                if !self.settings.remember_window_size_per_tab {
+                    return save;
+                }
+                // Only resize to a size the user previously chose for this tab; tabs never
+                // visited keep whatever size the window currently has.
+                match self.settings.tab_window_sizes.get(&tab) {
+                    Some(size) => Command::batch([save, window::resize(window::Id::MAIN, Size::new(size.width, size.height))]),
+                    None => save,
+                }
+            }
+            Message::WindowResized(size) => {
+                // Some platforms report a zero size while the window is minimized.
+                if size.width == 0.0 || size.height == 0.0 {
+                    return Command::none();
+                }
+                let per_tab_changed = self.settings.remember_window_size_per_tab
+                    && self.settings.tab_window_sizes.get(&self.active_tab) != Some(&size);
+                if self.settings.last_window_size == Some(size) && !per_tab_changed {
+                    return Command::none();
+                }
+                self.settings.last_window_size = Some(size);
+                if per_tab_changed {
+                    self.settings.tab_window_sizes.insert(self.active_tab, size);
+                }
+                self.schedule_save()
+            }
+            Message::FlushSettings(generation) => {
+                // A newer change restarted the quiet period; its own flush will write.
+                if !self.pending_save.flush(generation) {
+                    return Command::none();
+                }
+                Command::perform(self.settings.clone().save(), Message::SettingsSaved)
+            }
+            Message::PopOutGraph(_) | Message::CompareProcessPinned(_) if self.is_replaying => {
+                self.show_status(StatusMessage::error("Not available while replaying a snapshot ⚠️"), Duration::from_secs(3))
+            }
+            Message::PopOutGraph(pid) => {
+                let Some(process) = self.system.process(pid) else {
+                    let err_msg = format!("No process with PID {} ⚠️", pid);
+                    return self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3));
+                };
+                let graph = ProcessGraphWindow {
+                    pid,
+                    name: process.name().to_string(),
+                    cpu_history: VecDeque::with_capacity(GRAPH_HISTORY_LEN),
+                    mem_history: VecDeque::with_capacity(GRAPH_HISTORY_LEN),
+                    ended: false,
+                };
+                let (id, spawn) = window::spawn(window::Settings {
+                    size: Size::new(700.0, 500.0),
+                    ..Default::default()
+                });
+                self.graph_windows.insert(id, graph);
+                spawn
+            }
+            Message::SampleGraphs => {
+                let cpu_cap = self.cpu_cap();
+                for graph in self.graph_windows.values_mut().filter(|graph| !graph.ended) {
+                    if !self.system.refresh_process(graph.pid) {
+                        graph.ended = true;
+                        continue;
+                    }
+                    let Some(process) = self.system.process(graph.pid) else {
+                        graph.ended = true;
+                        continue;
+                    };
+                    if graph.cpu_history.len() == GRAPH_HISTORY_LEN {
+                        graph.cpu_history.pop_front();
+                        graph.mem_history.pop_front();
+                    }
+                    graph.cpu_history.push_back(cap_cpu(process.cpu_usage(), cpu_cap));
+                    graph.mem_history.push_back(process.memory() as f32 / (1024.0 * 1024.0));
+                }
+                Command::none()
+            }
+            Message::WindowClosed(id) => {
+                self.graph_windows.remove(&id);
+                Command::none()
+            }
+            Message::CopyVisiblePids => {
+                let pids: Vec<String> = self.visible_processes()
+                    .into_iter()
+                    .take(self.settings.process_row_limit)
+                    .filter(|p| p.exited_at.is_none())
+                    .map(|p| p.pid.to_string())
+                    .collect();
+                if pids.is_empty() {
+                    return self.show_status(StatusMessage::error("No visible processes to copy ⚠️"), Duration::from_secs(3));
+                }
+                let success_msg = format!("Copied {} PIDs to clipboard ✅", pids.len());
+                Command::batch([
+                    iced::clipboard::write(pids.join(" ")),
+                    self.show_status(StatusMessage::success(&success_msg), Duration::from_secs(3)),
+                ])
+            }
+            Message::AutoRulesEnableRequested(false) => {
+                self.settings.auto_rules_enabled = false;
+                self.rule_breaches.clear();
+                tracing::info!("Automatic rules disabled");
+                self.schedule_save()
+            }
+            Message::AutoRulesEnableRequested(true) => {
+                self.show_auto_rules_confirm = true;
+                Command::none()
+            }
+            Message::AutoRulesEnableConfirmed => {
+                self.show_auto_rules_confirm = false;
+                self.settings.auto_rules_enabled = true;
+                tracing::warn!("Automatic rules enabled ({} rules)", self.settings.auto_rules.len());
+                self.schedule_save()
+            }
+            Message::AutoRulesEnableCancelled => {
+                self.show_auto_rules_confirm = false;
+                Command::none()
+            }
+            Message::RuleDraftChanged(field) => {
+                match field {
+                    RuleDraftField::NamePattern(value) => self.rule_draft.name_pattern = value,
+                    RuleDraftField::Metric(metric) => self.rule_draft.metric = metric,
+                    RuleDraftField::Threshold(value) => self.rule_draft.threshold = value,
+                    RuleDraftField::Duration(value) => self.rule_draft.duration_secs = value,
+                    RuleDraftField::Action(action) => self.rule_draft.action = action,
+                }
+                Command::none()
+            }
+            Message::AddAutoRule => match self.rule_draft.to_rule() {
+                Ok(rule) => {
+                    tracing::info!("Added automatic rule: {}", rule.describe());
+                    self.settings.auto_rules.push(rule);
+                    self.rule_draft = RuleDraft::default();
+                    self.schedule_save()
+                }
+                Err(e) => {
+                    self.show_status(StatusMessage::error(&format!("{} ⚠️", e)), Duration::from_secs(3))
+                }
+            },
+            Message::RemoveAutoRule(index) => {
+                if index < self.settings.auto_rules.len() {
+                    let rule = self.settings.auto_rules.remove(index);
+                    tracing::info!("Removed automatic rule: {}", rule.describe());
+                    // Breach timers are keyed by rule index, which just shifted.
+                    self.rule_breaches.clear();
+                }
+                self.schedule_save()
+            }
+            Message::SelectableTextEdited => Command::none(),
+            Message::CompareProcessPinned(pid) => {
+                self.compare_process = Some(pid);
+                self.show_status(StatusMessage::success("Pinned — select another process to compare"), Duration::from_secs(3))
+            }
+            Message::GroupingModeChanged(mode) => {
+                self.settings.grouping_mode = mode;
+                self.expanded_groups.clear();
+                self.expanded_tree_nodes.clear();
+                self.schedule_save()
+            }
+            Message::CpuCapToggled(enabled) => {
+                self.settings.cap_process_cpu = enabled;
+                self.schedule_save()
+            }
+            Message::MemoryMetricChanged(metric) => {
+                self.settings.memory_metric = metric;
+                let (memory_used, memory_metric) = metric.read(&self.system);
+                self.dashboard_data.memory_used = memory_used;
+                self.dashboard_data.memory_metric = memory_metric;
+                self.dashboard_data.memory_other = memory_metric.read_other(&self.system);
+                self.schedule_save()
+            }
+            Message::MemoryUnitChanged(unit) => {
+                self.settings.memory_unit = unit;
+                self.schedule_save()
+            }
+            Message::CompactModeToggled(enabled) => {
+                self.settings.compact_mode = enabled;
+                self.schedule_save()
+            }
+            Message::ZebraStripingToggled(enabled) => {
+                self.settings.zebra_striping = enabled;
+                self.schedule_save()
+            }
+            Message::RowGridlinesToggled(enabled) => {
+                self.settings.row_gridlines = enabled;
+                self.schedule_save()
+            }
+            Message::MemoryBarsToggled(enabled) => {
+                self.settings.show_memory_bars = enabled;
+                self.schedule_save()
+            }
+            Message::GroupToggled(key) => {
+                if !self.expanded_groups.remove(&key) {
+                    self.expanded_groups.insert(key);
+                }
+                Command::none()
+            }
+            Message::TreeNodeToggled(pid) => {
+                if !self.expanded_tree_nodes.remove(&pid) {
+                    self.expanded_tree_nodes.insert(pid);
+                }
+                Command::none()
+            }
+            Message::TreeDepthLimitChanged(depth) => {
+                self.settings.tree_depth_limit = depth;
+                self.schedule_save()
+            }
+            Message::CompareCleared => {
+                self.compare_process = None;
+                Command::none()
+            }
+            Message::AutoExportTick => {
+                let path = self.auto_export_dir().join(format!(
+                    "processes_{}.{}",
+                    chrono::Local::now().format(AUTO_EXPORT_TIMESTAMP_FORMAT),
+                    self.settings.auto_export_format.extension(),
+                ));
+                Command::perform(
+                    App::save_export(self.export_rows(), path, self.settings.auto_export_format),
+                    Message::AutoExportSaved,
+                )
+            }
+            Message::AutoExportSaved(Ok(path_str)) => {
+                tracing::info!("Auto-exported process list to: {}", path_str);
+                self.last_auto_export = Some(chrono::Local::now());
+                self.prune_exports_command()
+            }
+            Message::ExportsPruned(Ok(0)) => Command::none(),
+            Message::ExportsPruned(Ok(count)) => {
+                tracing::info!("Pruned {} old export files", count);
+                let msg = format!("Pruned {} old export file{} 🧹", count, if count == 1 { "" } else { "s" });
+                self.show_status(StatusMessage::success(&msg), Duration::from_secs(3))
+            }
+            Message::ExportsPruned(Err(e)) => {
+                tracing::error!("Failed to prune old exports: {}", e);
+                Command::none()
+            }
+            Message::RefreshIntervalChanged(secs) => {
+                self.settings.refresh_interval_secs = secs;
+                self.schedule_save()
+            }
+            Message::ExportRetentionFilesChanged(max_files) => {
+                self.settings.export_retention_max_files = max_files;
+                Command::batch([
+                    self.schedule_save(),
+                    self.prune_exports_command(),
+                ])
+            }
+            Message::ExportRetentionDaysChanged(days) => {
+                self.settings.export_retention_max_age_days = days;
+                Command::batch([
+                    self.schedule_save(),
+                    self.prune_exports_command(),
+                ])
+            }
+            Message::AutoExportSaved(Err(e)) => {
+                tracing::error!("Scheduled export failed: {}", e);
+                self.show_status(StatusMessage::error("Scheduled export failed ⚠️"), Duration::from_secs(3))
+            }
+            Message::AutoExportToggled(enabled) => {
+                self.settings.auto_export_enabled = enabled;
+                self.schedule_save()
+            }
+            Message::AutoExportIntervalChanged(mins) => {
+                self.settings.auto_export_interval_mins = mins;
+                self.schedule_save()
+            }
+            Message::AutoExportFormatChanged(format) => {
+                self.settings.auto_export_format = format;
+                self.schedule_save()
+            }
+            Message::AutoExportDirChanged(dir) => {
+                self.settings.auto_export_dir = dir;
+                Command::none()
+            }
+            Message::AutoExportDirSubmitted => {
+                self.schedule_save()
+            }
+            Message::ScreenshotRequested => window::screenshot(window::Id::MAIN, Message::ScreenshotCaptured),
+            Message::ScreenshotCaptured(screenshot) => {
+                let path = self.screenshot_path();
+                Command::perform(App::save_screenshot(screenshot, path), Message::ScreenshotSaved)
+            }
+            Message::ScreenshotSaved(Ok(path_str)) => {
+                tracing::info!("Screenshot saved to: {}", path_str);
+                let success_msg = format!("Screenshot saved to {} ✅", path_str);
+                self.show_status(StatusMessage::success(&success_msg), Duration::from_secs(3))
+            }
+            Message::ScreenshotSaved(Err(e)) => {
+                tracing::error!("Failed to save screenshot: {}", e);
+                self.show_status(StatusMessage::error("Failed to save screenshot ⚠️"), Duration::from_secs(3))
+            }
+            Message::ScreenshotDirChanged(dir) => {
+                self.settings.screenshot_dir = dir;
+                Command::none()
+            }
+            Message::ScreenshotDirSubmitted => {
+                self.schedule_save()
+            }
+            Message::ColumnVisibilityToggled(column, visible) => {
+                if visible {
+                    self.settings.visible_columns.insert(column);
+                } else if self.settings.visible_columns.len() > 1 {
+                    self.settings.visible_columns.remove(&column);
+                } else {
+                    return Command::none();
+                }
+                self.schedule_save()
+            }
+            Message::ProcessGracePeriodChanged(grace_ms) => {
+                self.settings.process_grace_period_ms = grace_ms;
+                self.schedule_save()
+            }
+            Message::NumberGroupingChanged(grouping) => {
+                self.settings.number_grouping = grouping;
+                self.schedule_save()
+            }
+            Message::AnimationFrame => {
+                self.displayed_cpu_usage = ease_toward(self.displayed_cpu_usage, self.dashboard_data.cpu_usage as f64, 0.05);
+                self.displayed_memory_used = ease_toward(
+                    self.displayed_memory_used,
+                    self.dashboard_data.memory_used as f64,
+                    0.005 * BYTES_PER_GIB,
+                );
+                Command::none()
+            }
+            Message::MetricsEndpointToggled(enabled) => {
+                self.settings.metrics_endpoint_enabled = enabled;
+                Command::batch([
+                    self.schedule_save(),
+                    self.sync_metrics_server(),
+                ])
+            }
+            Message::MetricsPortInputChanged(input) => {
+                self.metrics_port_input = input;
+                Command::none()
+            }
+            Message::MetricsPortSubmitted => match self.metrics_port_input.trim().parse::<u16>() {
+                Ok(port) if port > 0 => {
+                    self.settings.metrics_port = port;
+                    Command::batch([
+                        self.schedule_save(),
+                        self.sync_metrics_server(),
+                    ])
+                }
+                _ => {
+                    let err_msg = format!("\"{}\" is not a valid port ⚠️", self.metrics_port_input.trim());
+                    self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3))
+                }
+            },
+            Message::MetricsServerStopped(_, Ok(())) => Command::none(),
+            Message::MetricsServerStopped(port, Err(e)) => {
+                tracing::error!("Metrics endpoint on port {} stopped: {}", port, e);
+                // A server that was already replaced says nothing about the current one.
+                if self.metrics_server.as_ref().map(|(running, _)| *running) != Some(port) {
+                    return Command::none();
+                }
+                // The saved opt-in stays: a busy port now may be free on the next launch or
+                // the next port change, which retries through `sync_metrics_server`.
+                self.metrics_server = None;
+                let err_msg = format!("Metrics endpoint failed: {} ⚠️", e);
+                self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(5))
+            }
+            Message::ProfileSelected(name) => {
+                let Some(profile) = self.all_profiles().into_iter().find(|p| p.name == name) else {
+                    return Command::none();
+                };
+                match profile.apply(&self.settings) {
+                    Ok(mut settings) => {
+                        settings.active_profile = Some(name.clone());
+                        self.settings = settings;
+                        self.profile_name_input = name.clone();
+                        self.metrics_port_input = self.settings.metrics_port.to_string();
+                        Command::batch([
+                            self.schedule_save(),
+                            self.sync_metrics_server(),
+                            self.show_status(
+                                StatusMessage::success(&format!("Switched to profile \"{}\" ✅", name)),
+                                Duration::from_secs(3),
+                            ),
+                        ])
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to apply profile {}: {}", name, e);
+                        let err_msg = format!("Profile \"{}\" is invalid: {} ⚠️", name, e);
+                        self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(5))
+                    }
+                }
+            }
+            Message::ProfileNameInputChanged(input) => {
+                self.profile_name_input = input;
+                Command::none()
+            }
+            Message::SaveProfileRequested => {
+                let name = self.profile_name_input.trim().to_string();
+                if let Err(e) = self.check_profile_name(&name, None) {
+                    return self.profile_error(&e);
+                }
+                let profile = match SettingsProfile::capture(&name, &self.settings) {
+                    Ok(profile) => profile,
+                    Err(e) => return self.profile_error(&e),
+                };
+                // Saving under an existing user profile's name updates it in place.
+                match self.settings.profiles.iter_mut().find(|p| p.name == name) {
+                    Some(existing) => *existing = profile,
+                    None => self.settings.profiles.push(profile),
+                }
+                self.settings.active_profile = Some(name.clone());
+                Command::batch([
+                    self.schedule_save(),
+                    self.show_status(StatusMessage::success(&format!("Saved profile \"{}\" ✅", name)), Duration::from_secs(3)),
+                ])
+            }
+            Message::RenameProfileRequested => {
+                let new_name = self.profile_name_input.trim().to_string();
+                let Some(old_name) = self.active_user_profile() else {
+                    return self.profile_error("select one of your own profiles to rename");
+                };
+                if let Err(e) = self.check_profile_name(&new_name, Some(&old_name)) {
+                    return self.profile_error(&e);
+                }
+                if let Some(profile) = self.settings.profiles.iter_mut().find(|p| p.name == old_name) {
+                    profile.name = new_name.clone();
+                }
+                self.settings.active_profile = Some(new_name);
+                self.schedule_save()
+            }
+            Message::DeleteProfileRequested => {
+                let Some(name) = self.active_user_profile() else {
+                    return self.profile_error("select one of your own profiles to delete");
+                };
+                self.settings.profiles.retain(|p| p.name != name);
+                self.settings.active_profile = None;
+                self.profile_name_input.clear();
+                Command::batch([
+                    self.schedule_save(),
+                    self.show_status(StatusMessage::success(&format!("Deleted profile \"{}\"", name)), Duration::from_secs(3)),
+                ])
+            }
+            Message::ChangelogDismissed => {
+                self.settings.last_seen_version = APP_VERSION.to_string();
+                self.schedule_save()
+            }
+            Message::ReduceMotionToggled(enabled) => {
+                self.settings.reduce_motion = enabled;
+                self.snap_card_values();
+                self.schedule_save()
+            }
+            Message::GraphsToggled(enabled) => {
+                self.settings.show_graphs = enabled;
+                self.schedule_save()
+            }
+            Message::StartInTrayToggled(enabled) => {
+                self.settings.start_in_tray = enabled;
+                self.schedule_save()
+            }
+            Message::CloseRequested => {
+                if self.hides_to_tray() {
+                    self.set_window_hidden(true)
+                } else {
+                    self.quit()
+                }
+            }
+            Message::TrayPoll => self.handle_tray_events(),
+            Message::DeveloperToolsToggled(enabled) => {
+                self.settings.developer_tools_enabled = enabled;
+                self.schedule_save()
+            }
+            Message::DebuggerCommandChanged(command) => {
+                self.settings.debugger_command = command;
+                Command::none()
+            }
+            Message::DebuggerCommandSubmitted => {
+                self.schedule_save()
+            }
+            // Attaching stops the target under ptrace, so treat it like the other destructive actions.
+            Message::OpenInDebugger(_) if self.is_read_only() => self.read_only_blocked(),
+            Message::OpenInDebugger(pid) => {
+                let label = self.process_label(pid);
+                let result = match debugger_argv(&self.settings.debugger_command, pid) {
+                    Some(argv) => std::process::Command::new(&argv[0])
+                        .args(&argv[1..])
+                        .spawn()
+                        .map(reap_in_background)
+                        .map_err(|e| e.to_string()),
+                    None => Err("debugger command is empty".to_string()),
+                };
+                let status = match result {
+                    Ok(()) => StatusMessage::success(&format!("Launched debugger for {} 🐞", label)),
+                    Err(e) => {
+                        tracing::error!("Failed to launch debugger for {}: {}", pid, e);
+                        StatusMessage::error(&format!("Failed to launch debugger for {} ⚠️ ({})", label, e))
+                    }
+                };
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::LiveWindowTitleToggled(enabled) => {
+                self.settings.live_window_title = enabled;
+                self.schedule_save()
+            }
+            Message::KillConfirmModeChanged(mode) => {
+                self.settings.kill_confirm_mode = mode;
+                self.schedule_save()
+            }
+            Message::ReadOnlyToggled(enabled) => {
+                self.settings.read_only = enabled;
+                if enabled {
+                    self.relaunch_offer = None;
+                }
+                self.schedule_save()
+            }
+            Message::ProcessRowLimitChanged(limit) => {
+                self.settings.process_row_limit = limit;
+                self.schedule_save()
+            }
+            Message::RememberWindowSizeToggled(enabled) => {
+                self.settings.remember_window_size_per_tab = enabled;
+                if !enabled {
+                    self.settings.tab_window_sizes.clear();
+                }
+                self.schedule_save()
+            }
+            Message::ProcessSelected(pid) => {
+                self.select_process(pid);
+                Command::none()
+            }
+            Message::PidJumpInputChanged(input) => {
+                self.pid_jump_input = input;
+                Command::none()
+            }
+            Message::SortBy(column) => {
+                if self.sort_column == column {
+                    self.sort_ascending = !self.sort_ascending;
+                } else {
+                    self.sort_column = column;
+                    self.sort_ascending = column.default_ascending();
+                }
+                Command::none()
+            }
+            Message::ProcessFilterChanged(filter) => {
+                self.process_filter = filter;
+                self.deselect_if_filtered_out();
+                Command::none()
+            }
+            Message::FilterCommandLineToggled(enabled) => {
+                self.filter_command_line = enabled;
+                self.deselect_if_filtered_out();
+                Command::none()
+            }
+            Message::UserFilterChanged(user) => {
+                self.user_filter = user;
+                self.deselect_if_filtered_out();
+                Command::none()
+            }
+            Message::MinCpuFilterChanged(input) => {
+                self.min_cpu_input = input;
+                self.deselect_if_filtered_out();
+                Command::none()
+            }
+            Message::MinMemFilterChanged(input) => {
+                self.min_mem_input = input;
+                self.deselect_if_filtered_out();
+                Command::none()
+            }
+            Message::PidJumpSubmitted => match self.pid_jump_input.trim().parse::<usize>() {
+                Ok(raw_pid) => self.update(Message::JumpToProcess(Pid::from(raw_pid))),
+                Err(_) => {
+                    let err_msg = format!("\"{}\" is not a valid PID ⚠️", self.pid_jump_input.trim());
+                    self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3))
+                }
+            },
+            Message::JumpToProcess(pid) => {
+                if self.live_process(pid).is_none() && self.replayed_row(pid).is_none() {
+                    let err_msg = format!("No process with PID {} ⚠️", pid);
+                    return self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3));
+                }
+                let hidden = |app: &Self| detail_row(&app.process_list, pid).is_some_and(|p| !app.passes_filters(p));
+                if hidden(self) {
+                    // Jumping to a PID the filters hide would select an invisible row.
+                    self.process_filter.clear();
+                    self.min_cpu_input.clear();
+                    self.min_mem_input.clear();
+                    self.user_filter = None;
+                }
+                if hidden(self) {
+                    let err_msg = format!("PID {} is hidden by the table filters ⚠️", pid);
+                    return self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3));
+                }
+                self.reveal_in_table(pid);
+                self.select_process(pid);
+                self.pid_jump_input.clear();
+                let tab_command = self.update(Message::TabSelected(Tab::Processes));
+                let mut rendered = self.table_order();
+                let listed = rendered.contains(&Some(pid));
+                rendered.truncate(self.settings.process_row_limit);
+                let scroll_command = match rendered.iter().position(|row| *row == Some(pid)) {
+                    Some(index) if rendered.len() > 1 => {
+                        let y = index as f32 / (rendered.len() - 1) as f32;
+                        scrollable::snap_to(process_table_id(), scrollable::RelativeOffset { x: 0.0, y })
+                    }
+                    None if listed => {
+                        let err_msg = format!(
+                            "{} is past the {}-row limit; refine the filter to see its row",
+                            self.process_label(pid),
+                            self.settings.process_row_limit,
+                        );
+                        self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(5))
+                    }
+                    _ => Command::none(),
+                };
+                Command::batch([tab_command, scroll_command])
+            }
+            Message::KillProcessRequested(_) | Message::KillProcessConfirmed(_) if self.is_read_only() => {
+                self.show_kill_confirm = None;
+                self.read_only_blocked()
+            }
+            Message::KillProcessRequested(pid) => {
+                if !self.settings.kill_confirm_mode.requires_confirmation(false) {
+                    return self.update(Message::KillProcessConfirmed(pid));
+                }
+                self.show_kill_confirm = Some(pid);
+                Command::none()
+            }
+            // While a confirmation is open, only Escape (which cancels it) gets through.
+            Message::KeyboardShortcut(message) => {
+                if self.confirmation_open() && !matches!(*message, Message::KillProcessCancelled) {
+                    return Command::none();
+                }
+                self.update(*message)
+            }
+            Message::TogglePause => {
+                self.is_paused = !self.is_paused;
+                if !self.is_paused {
+                    // Resume from current data rather than waiting for the next tick.
+                    self.refresh_metrics();
+                    self.refresh_processes();
+                    self.snap_card_values();
+                }
+                Command::none()
+            }
+            Message::UsageAlertsToggled(enabled) => {
+                self.settings.usage_alerts_enabled = enabled;
+                self.cpu_alert = UsageAlert::default();
+                self.mem_alert = UsageAlert::default();
+                self.schedule_save()
+            }
+            Message::CpuAlertThresholdChanged(threshold) => {
+                self.settings.cpu_alert_threshold = threshold;
+                Command::none()
+            }
+            Message::MemAlertThresholdChanged(threshold) => {
+                self.settings.mem_alert_threshold = threshold;
+                Command::none()
+            }
+            // Sliders only save once released instead of on every drag step.
+            Message::AlertThresholdReleased => self.schedule_save(),
+            Message::KillSelectedRequested => match self.selected_process {
+                Some(pid) if self.active_tab == Tab::Processes => self.update(Message::KillProcessRequested(pid)),
+                _ => Command::none(),
+            },
+            Message::KillProcessCancelled => {
+                self.show_kill_confirm = None;
+                self.show_bulk_kill_confirm = None;
+                self.show_signal_confirm = None;
+                self.show_restart_confirm = None;
+                self.show_auto_rules_confirm = false;
+                self.context_menu = None;
+                self.show_about = false;
+                Command::none()
+            }
+            Message::ShowAbout => {
+                self.show_about = true;
+                Command::none()
+            }
+            Message::HideAbout => {
+                self.show_about = false;
+                Command::none()
+            }
+            Message::ProcessContextMenu(pid) => {
+                self.context_menu = Some(pid);
+                Command::none()
+            }
+            Message::ContextMenuDismissed => {
+                self.context_menu = None;
+                Command::none()
+            }
+            Message::CopyToClipboard(value) => {
+                let copied = format!("Copied \"{}\" to clipboard ✅", value);
+                Command::batch([
+                    iced::clipboard::write(value),
+                    self.show_status(StatusMessage::success(&copied), Duration::from_secs(3)),
+                ])
+            }
+            Message::SignalSelected(signal) => {
+                self.selected_signal = signal;
+                Command::none()
+            }
+            Message::SendSignal(..) | Message::SignalConfirmed(..) if self.is_read_only() => {
+                self.show_signal_confirm = None;
+                self.read_only_blocked()
+            }
+            Message::SendSignal(pid, signal) => {
+                if !self.settings.kill_confirm_mode.requires_confirmation(false) {
+                    return self.update(Message::SignalConfirmed(pid, signal));
+                }
+                self.show_signal_confirm = Some((pid, signal));
+                Command::none()
+            }
+            Message::SignalConfirmed(pid, signal) => {
+                self.show_signal_confirm = None;
+                let label = self.process_label(pid);
+                let result = match self.system.process(pid) {
+                    Some(process) => send_signal(process, signal),
+                    None => Err("no such process".to_string()),
+                };
+                let status = match result {
+                    Ok(()) => {
+                        if signal == Signal::Kill {
+                            self.process_list.retain(|p| p.pid != pid);
+                        }
+                        StatusMessage::success(&format!("Sent {} to {} ✅", signal_name(signal), label))
+                    }
+                    Err(reason) => {
+                        tracing::error!("Failed to send {} to {}: {}", signal_name(signal), pid, reason);
+                        StatusMessage::error(&format!("Failed to send {} to {} ⚠️ ({})", signal_name(signal), label, reason))
+                    }
+                };
+                self.refresh_if_paused();
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::KillProcessConfirmed(pid) => {
+                self.show_kill_confirm = None; 
+                self.relaunch_offer = None;
+                let label = self.process_label(pid);
+                let status_message = if let Some(process) = self.system.process(pid) {
+                    let relaunch = RelaunchCommand::capture(process);
+                    match kill_process(process) {
+                        Ok(()) => {
+                            self.relaunch_offer = relaunch;
+                            // Killed rows skip the churn grace period and disappear right away.
+                            self.process_list.retain(|p| p.pid != pid);
+                            StatusMessage::success(&format!("{} killed successfully ✅", label))
+                        }
+                        Err(reason) => {
+                            tracing::error!("Failed to kill process {}: {}", pid, reason);
+                            let err_msg = format!("Failed to kill {} ⚠️ ({})", label, reason);
+                            StatusMessage::error(&err_msg)
+                        }
+                    }
+                } else {
+                    let err_msg = format!("Tried to kill non-existent process {}", pid);
+                    StatusMessage::error(&err_msg)
+                };
+                self.refresh_if_paused();
+                self.show_status(status_message, Duration::from_secs(3))
+            }
+            Message::KillGroupRequested(..) | Message::KillGroupConfirmed if self.is_read_only() => {
+                self.show_bulk_kill_confirm = None;
+                self.read_only_blocked()
+            }
+            Message::KillGroupRequested(label, pids) => {
+                self.show_bulk_kill_confirm = Some((label, pids));
+                if !self.settings.kill_confirm_mode.requires_confirmation(true) {
+                    return self.update(Message::KillGroupConfirmed);
+                }
+                Command::none()
+            }
+            Message::KillGroupConfirmed => {
+                let Some((label, pids)) = self.show_bulk_kill_confirm.take() else {
+                    return Command::none();
+                };
+                self.relaunch_offer = None;
+                let mut killed = Vec::new();
+                let mut failures = Vec::new();
+                for pid in &pids {
+                    match self.system.process(*pid).map(kill_process) {
+                        Some(Ok(())) => killed.push(*pid),
+                        Some(Err(reason)) => {
+                            tracing::error!("Failed to kill process {}: {}", pid, reason);
+                            failures.push(reason);
+                        }
+                        // Already gone, which is what was asked for.
+                        None => {}
+                    }
+                }
+                self.process_list.retain(|p| !killed.contains(&p.pid));
+                let status = match failures.first() {
+                    None => StatusMessage::success(&format!("Killed {} {} processes ✅", killed.len(), label)),
+                    Some(reason) => StatusMessage::error(&format!(
+                        "Killed {} of {} {} processes ⚠️ ({})",
+                        killed.len(),
+                        pids.len(),
+                        label,
+                        reason,
+                    )),
+                };
+                self.refresh_if_paused();
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::RestartProcess(_) | Message::RestartConfirmed(_) if self.is_read_only() => {
+                self.show_restart_confirm = None;
+                self.read_only_blocked()
+            }
+            Message::RestartProcess(pid) => {
+                self.show_restart_confirm = Some(pid);
+                Command::none()
+            }
+            Message::RestartConfirmed(pid) => {
+                self.show_restart_confirm = None;
+                self.relaunch_offer = None;
+                let label = self.process_label(pid);
+                // The launch details have to be read now: they are gone once the process is.
+                let captured = match self.system.process(pid) {
+                    None => Err("no such process".to_string()),
+                    Some(process) if process.exe().is_none() => Err("executable path unknown".to_string()),
+                    Some(process) => RelaunchCommand::capture(process)
+                        .ok_or_else(|| "no command line captured".to_string())
+                        .and_then(|relaunch| kill_process(process).map(|()| relaunch)),
+                };
+                match captured {
+                    Ok(relaunch) => {
+                        self.process_list.retain(|p| p.pid != pid);
+                        self.last_status_message = Some(StatusMessage::success(&format!("Killed {}, restarting…", label)));
+                        self.refresh_if_paused();
+                        Command::perform(tokio::time::sleep(RESTART_DELAY), move |_| Message::RestartRelaunch(relaunch))
+                    }
+                    Err(reason) => {
+                        tracing::error!("Failed to restart process {}: {}", pid, reason);
+                        self.show_status(
+                            StatusMessage::error(&format!("Can't restart {} ⚠️ ({})", label, reason)),
+                            Duration::from_secs(3),
+                        )
+                    }
+                }
+            }
+            Message::RestartRelaunch(relaunch) => {
+                let status = match relaunch.spawn() {
+                    Ok(child_pid) => StatusMessage::success(&format!("Restarted {} (new PID {}) ✅", relaunch.name, child_pid)),
+                    Err(e) => {
+                        tracing::error!("Failed to start {} again after restart: {}", relaunch.name, e);
+                        StatusMessage::error(&format!("Killed {} but failed to start it again ⚠️ ({})", relaunch.name, e))
+                    }
+                };
+                self.refresh_if_paused();
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::ClearStatusMessage(id) => {
+                if self.last_status_message.as_ref().is_some_and(|status| status.id == id) {
+                    self.last_status_message = None;
+                    self.relaunch_offer = None;
+                }
+                Command::none()
+            }
+            Message::RelaunchRequested => {
+                let Some(relaunch) = self.relaunch_offer.take() else {
+                    return Command::none();
+                };
+                let status = match relaunch.spawn() {
+                    Ok(child_pid) => StatusMessage::success(&format!("Relaunched {} (PID {}) ✅", relaunch.name, child_pid)),
+                    Err(e) => {
+                        tracing::error!("Failed to relaunch {}: {}", relaunch.name, e);
+                        StatusMessage::error(&format!("Failed to relaunch {} ⚠️ ({})", relaunch.name, e))
+                    }
+                };
+                self.show_status(status, Duration::from_secs(3))
+            }
+            Message::ExportCsvRequested => {
+                Command::perform(App::save_csv(self.export_rows()), Message::ExportCsvSaved)
+            }
+            Message::ExportSnapshot => {
+                // Disks are otherwise only refreshed while their tab is open.
+                self.refresh_disks();
+                let snapshot = SystemSnapshot {
+                    app_version: APP_VERSION.into(),
+                    timestamp: chrono::Local::now().to_rfc3339(),
+                    system: self.dashboard_data.clone(),
+                    processes: self.process_list.iter()
+                        .filter(|p| p.exited_at.is_none())
+                        .map(SnapshotProcess::capture)
+                        .collect(),
+                    networks: self.network_data.clone(),
+                    disks: self.disk_data.clone(),
+                };
+                Command::perform(App::save_snapshot(snapshot), Message::SnapshotSaved)
+            }
+            Message::SnapshotSaved(Ok(path_str)) => {
+                tracing::info!("Snapshot saved to: {}", path_str);
+                self.show_status(StatusMessage::success(&format!("Snapshot saved to {} ✅", path_str)), Duration::from_secs(3))
+            }
+            Message::SnapshotSaved(Err(e)) => {
+                tracing::error!("Failed to save snapshot: {}", e);
+                self.show_status(StatusMessage::error("Failed to save snapshot ⚠️"), Duration::from_secs(3))
+            }
+            Message::SnapshotPathChanged(path) => {
+                self.snapshot_path_input = path;
+                Command::none()
+            }
+            Message::LoadSnapshot => {
+                let path = self.snapshot_path_input.trim();
+                let path = (!path.is_empty()).then(|| PathBuf::from(path));
+                Command::perform(App::load_snapshot(path), Message::SnapshotLoaded)
+            }
+            Message::SnapshotLoaded(Ok((path_str, snapshot))) => {
+                tracing::info!("Replaying snapshot from: {}", path_str);
+                self.start_replay(&path_str, *snapshot);
+                self.show_status(StatusMessage::success(&format!("Loaded snapshot {} ✅", path_str)), Duration::from_secs(3))
+            }
+            Message::SnapshotLoaded(Err(e)) => {
+                tracing::error!("Failed to load snapshot: {}", e);
+                self.show_status(StatusMessage::error(&format!("Failed to load snapshot ⚠️ ({})", e)), Duration::from_secs(3))
+            }
+            Message::ExitReplay => {
+                self.is_replaying = false;
+                self.replay_info.clear();
+                self.process_list.clear();
+                self.refresh()
+            }
+            Message::ExportCsvSaved(Ok(path_str)) => {
+                tracing::info!("CSV saved successfully to: {}", path_str);
+                let success_msg = format!("CSV exported to {} ✅", path_str);
+                self.show_status(StatusMessage::success(&success_msg), Duration::from_secs(3))
+            }
+            Message::ExportCsvSaved(Err(e)) => {
+                tracing::error!("Failed to save CSV: {}", e);
+                self.show_status(StatusMessage::error("Failed to export CSV ⚠️"), Duration::from_secs(3))
+            }
+            Message::CopySettingsRequested => match self.settings.to_json() {
+                Ok(json) => {
+                    Command::batch([
+                        iced::clipboard::write(json),
+                        self.show_status(StatusMessage::success("Settings copied to clipboard ✅"), Duration::from_secs(3)),
+                    ])
+                }
+                Err(e) => {
+                    tracing::error!("Failed to serialize settings: {}", e);
+                    self.show_status(StatusMessage::error("Failed to copy settings ⚠️"), Duration::from_secs(3))
+                }
+            },
+            Message::CopyDiagnostics => {
+                let report = diagnostics_report(
+                    &OsInfo::collect(&self.system),
+                    &self.dashboard_data,
+                    &self.process_list,
+                    &self.rebuild_timing,
+                    &self.settings,
+                );
+                Command::batch([
+                    iced::clipboard::write(report),
+                    self.show_status(StatusMessage::success("Diagnostics copied to clipboard ✅"), Duration::from_secs(3)),
+                ])
+            }
+            Message::PasteSettingsRequested => iced::clipboard::read(Message::SettingsPasted),
+            Message::SettingsPasted(None) => {
+                self.show_status(StatusMessage::error("Clipboard is empty ⚠️"), Duration::from_secs(3))
+            }
+            Message::SettingsPasted(Some(content)) => match AppSettings::from_json(&content) {
+                Ok(settings) => {
+                    tracing::info!("Pasted settings parsed, backing up current settings...");
+                    Command::perform(self.settings.clone().backup(), move |result| {
+                        Message::SettingsBackedUp(result, settings.clone())
+                    })
+                }
+                Err(e) => {
+                    tracing::error!("Failed to parse pasted settings: {}", e);
+                    let err_msg = format!("Invalid settings in clipboard: {} ⚠️", e);
+                    self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(5))
+                }
+            },
+            Message::SettingsBackedUp(Ok(()), mut settings) => {
+                tracing::info!("Current settings backed up, applying pasted settings...");
+                if settings.auto_rules_enabled && !self.settings.auto_rules_enabled {
+                    // Enabling automatic rules always goes through the confirmation prompt.
+                    tracing::warn!("Pasted settings enable automatic rules; leaving them disabled");
+                    settings.auto_rules_enabled = false;
+                }
+                if settings.metrics_endpoint_enabled && !self.settings.metrics_endpoint_enabled {
+                    // Opening a network port is never switched on by pasted settings.
+                    tracing::warn!("Pasted settings enable the metrics endpoint; leaving it disabled");
+                    settings.metrics_endpoint_enabled = false;
+                }
+                self.settings = settings;
+                self.metrics_port_input = self.settings.metrics_port.to_string();
+                Command::batch([
+                    self.schedule_save(),
+                    self.sync_metrics_server(),
+                ])
+            }
+            Message::ProcessHovered(pid) => {
+                self.hovered_process = Some(pid);
+                self.tooltip_visible = self.settings.tooltip_delay_ms == 0;
+                if self.tooltip_visible {
+                    Command::none()
+                } else {
+                    let delay = Duration::from_millis(self.settings.tooltip_delay_ms);
+                    Command::perform(tokio::time::sleep(delay), move |_| Message::TooltipDelayElapsed(pid))
+                }
+            }
+            Message::ProcessHoverEnded(pid) => {
+                if self.hovered_process == Some(pid) {
+                    self.hovered_process = None;
+                    self.tooltip_visible = false;
+                }
+                Command::none()
+            }
+            Message::TooltipDelayElapsed(pid) => {
+                // A stale timer from a row the cursor already left must not reveal the tooltip.
+                if self.hovered_process == Some(pid) {
+                    self.tooltip_visible = true;
+                }
+                Command::none()
+            }
+            Message::TooltipDelayChanged(delay_ms) => {
+                self.settings.tooltip_delay_ms = delay_ms;
+                self.schedule_save()
+            }
+            Message::TooltipFieldToggled(field, enabled) => {
+                match field {
+                    TooltipField::Name => self.settings.tooltip_show_name = enabled,
+                    TooltipField::Exe => self.settings.tooltip_show_exe = enabled,
+                    TooltipField::Cmd => self.settings.tooltip_show_cmd = enabled,
+                }
+                self.schedule_save()
+            }
+            Message::SettingsBackedUp(Err(e), _) => {
+                tracing::error!("Failed to back up settings: {}", e);
+                self.show_status(
+                    StatusMessage::error("Failed to back up current settings, paste aborted ⚠️"),
+                    Duration::from_secs(3),
+                )
+            }
+        }
+    }
+
+    fn view(&self, window: window::Id) -> Element<'_, Message, Theme, Renderer> {
+        if let Some(graph) = self.graph_windows.get(&window) {
+            return self.view_process_graph(graph);
+        }
+
+        if self.is_background_sampling() {
+            // Nothing is visible while minimized; a full view is rebuilt on restore.
+            return Space::new(Length::Fill, Length::Fill).into();
+        }
+
+        if self.is_loading {
+            let content = text(self.tr(UiText::LoadingSettings))
                 .size(32)
                 .horizontal_alignment(alignment::Horizontal::Center);
             return Container::<Message, Theme, Renderer>::new(content)
                 .width(Length::Fill)
-                .height(Length::Fill)
-                .center_x()
-                .center_y()
-                .into();
+                .height(Length::Fill)
+                .center_x()
+                .center_y()
+                .into();
+        }
+
+        let mut tabs = row![
+            create_tab_button(self.tr(UiText::TabDashboard), Tab::Dashboard, self.active_tab),
+            create_tab_button(self.tr(UiText::TabProcesses), Tab::Processes, self.active_tab),
+            create_tab_button(self.tr(UiText::TabNetwork), Tab::Network, self.active_tab),
+            create_tab_button(self.tr(UiText::TabDisks), Tab::Disks, self.active_tab),
+            create_tab_button(self.tr(UiText::TabSettings), Tab::Settings, self.active_tab),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+        tabs = tabs.push(
+            Button::new(text(self.tr(UiText::Screenshot)))
+                .on_press(Message::ScreenshotRequested)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        );
+        tabs = tabs.push(
+            text_input(self.tr(UiText::GoToPid), &self.pid_jump_input)
+                .on_input(Message::PidJumpInputChanged)
+                .on_submit(Message::PidJumpSubmitted)
+                .width(Length::Fixed(120.0))
+                .padding(10),
+        );
+        tabs = tabs.push(
+            Button::new(text(self.tr(UiText::Refresh)))
+                .on_press(Message::ManualRefresh)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        );
+        tabs = tabs.push(
+            Button::new(text(self.tr(if self.is_paused { UiText::Resume } else { UiText::Pause })))
+                .on_press(Message::TogglePause)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        );
+        tabs = tabs.push(
+            Button::new(text("?"))
+                .on_press(Message::ShowAbout)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        );
+        if self.is_paused {
+            tabs = tabs.push(create_badge(self.tr(UiText::Paused), Color::from_rgb(0.2, 0.5, 0.8)));
+        }
+        if self.is_read_only() {
+            tabs = tabs.push(create_badge("🔒 Read-only", Color::from_rgb(0.8, 0.6, 0.1)));
+        } else if !self.settings.kill_confirm_mode.requires_confirmation(false) {
+            // Single kills skip confirmation, so make that hard to miss.
+            tabs = tabs.push(create_badge("⚡ Quick Kill ON", Color::from_rgb(0.85, 0.2, 0.2)));
+        }
+
+        let page_content = match self.active_tab {
+            Tab::Dashboard => self.view_dashboard(),
+            Tab::Processes => self.view_processes(),
+            Tab::Network => self.view_network(),
+            Tab::Disks => self.view_disks(),
+            Tab::Settings => self.view_settings(),
+        };
+        
+        let status_bar: Element<'_, Message, Theme, Renderer> = if let Some(status) = &self.last_status_message {
+            let (bg_color, text_color) = match status.level {
+                NotificationLevel::Success => (Color::from_rgb(0.2, 0.6, 0.2), Color::WHITE),
+                NotificationLevel::Error => (Color::from_rgb(0.8, 0.2, 0.2), Color::WHITE),
+            };
+            let message = text(status.message.clone())
+                .style(iced::theme::Text::Color(text_color))
+                .horizontal_alignment(alignment::Horizontal::Center);
+            let content: Element<'_, Message, Theme, Renderer> = match &self.relaunch_offer {
+                Some(relaunch) => row![
+                    message,
+                    Button::new(text(format!("Relaunch {}?", relaunch.name)))
+                        .on_press(Message::RelaunchRequested)
+                        .style(iced::theme::Button::Secondary),
+                ]
+                .spacing(15)
+                .align_items(Alignment::Center)
+                .into(),
+                None => message.into(),
+            };
+            Container::<Message, Theme, Renderer>::new(content)
+                .center_x()
+            .width(Length::Fill)
+            .padding(10)
+            .style(move |_theme: &Theme| container::Appearance {
+                background: Some(iced::Background::Color(bg_color)),
+                border: Border { radius: 5.0.into(), ..Default::default() },
+                ..Default::default()
+            })
+            .into()
+        } else {
+            Container::<Message, Theme, Renderer>::new(Space::with_height(0.0))
+                .padding(10)
+                .into()
+        };
+
+        let replay_banner: Element<'_, Message, Theme, Renderer> = if self.is_replaying {
+            Container::<Message, Theme, Renderer>::new(
+                row![
+                    text(format!("⏪ {} — live updates are paused", self.replay_info)).style(Color::WHITE),
+                    Button::new(text(self.tr(UiText::ExitReplay)))
+                        .on_press(Message::ExitReplay)
+                        .style(iced::theme::Button::Secondary),
+                ]
+                .spacing(15)
+                .align_items(Alignment::Center),
+            )
+            .center_x()
+            .width(Length::Fill)
+            .padding(10)
+            .style(|_theme: &Theme| container::Appearance {
+                background: Some(iced::Background::Color(Color::from_rgb(0.45, 0.3, 0.7))),
+                border: Border { radius: 5.0.into(), ..Default::default() },
+                ..Default::default()
+            })
+            .into()
+        } else {
+            Space::with_height(0).into()
+        };
+
+        let main_content = column![
+            tabs,
+            replay_banner,
+            Space::with_height(20),
+            page_content,
+            Space::with_height(10),
+            status_bar
+        ]
+        .spacing(10)
+        .padding(self.sizing().page_padding)
+        .align_items(Alignment::Center);
+
+        if let Some(pid_to_kill) = self.show_kill_confirm {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text(format!("Kill {}?", self.process_label(pid_to_kill))).size(24),
+                    Space::with_height(10),
+                    text("Are you sure? This action cannot be undone."),
+                    text(self.confirm_target_usage(pid_to_kill)),
+                    Space::with_height(20),
+                    row![
+                        Button::new(text("Cancel"))
+                            .on_press(Message::KillProcessCancelled)
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                        Button::new(text("Yes, Kill Process"))
+                            .on_press(Message::KillProcessConfirmed(pid_to_kill))
+                            .style(iced::theme::Button::Destructive)
+                            .padding(10),
+                    ].spacing(10).align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else if let Some((label, pids)) = &self.show_bulk_kill_confirm {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text(format!("Kill all {} {} processes?", pids.len(), label)).size(24),
+                    Space::with_height(10),
+                    text("Are you sure? This action cannot be undone."),
+                    Space::with_height(20),
+                    row![
+                        Button::new(text("Cancel"))
+                            .on_press(Message::KillProcessCancelled)
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                        Button::new(text("Yes, Kill All"))
+                            .on_press(Message::KillGroupConfirmed)
+                            .style(iced::theme::Button::Destructive)
+                            .padding(10),
+                    ].spacing(10).align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else if let Some((pid, signal)) = self.show_signal_confirm {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text(format!("Send {} to {}?", signal_name(signal), self.process_label(pid))).size(24),
+                    Space::with_height(10),
+                    text(signal_effect(signal)),
+                    text(self.confirm_target_usage(pid)),
+                    Space::with_height(20),
+                    row![
+                        Button::new(text("Cancel"))
+                            .on_press(Message::KillProcessCancelled)
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                        Button::new(text(format!("Send {}", signal_name(signal))))
+                            .on_press(Message::SignalConfirmed(pid, signal))
+                            .style(iced::theme::Button::Destructive)
+                            .padding(10),
+                    ].spacing(10).align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else if let Some(pid) = self.show_restart_confirm {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text(format!("Restart {}?", self.process_label(pid))).size(24),
+                    Space::with_height(10),
+                    text("The process is killed and started again with the same command line."),
+                    text(self.confirm_target_usage(pid)),
+                    Space::with_height(20),
+                    row![
+                        Button::new(text("Cancel"))
+                            .on_press(Message::KillProcessCancelled)
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                        Button::new(text("Yes, Restart Process"))
+                            .on_press(Message::RestartConfirmed(pid))
+                            .style(iced::theme::Button::Destructive)
+                            .padding(10),
+                    ].spacing(10).align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else if self.show_auto_rules_confirm {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text("Enable automatic rules?").size(24),
+                    Space::with_height(10),
+                    text("Rules with the Kill action will terminate matching processes without asking."),
+                    Space::with_height(20),
+                    row![
+                        Button::new(text("Cancel"))
+                            .on_press(Message::AutoRulesEnableCancelled)
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                        Button::new(text("Yes, Enable Rules"))
+                            .on_press(Message::AutoRulesEnableConfirmed)
+                            .style(iced::theme::Button::Destructive)
+                            .padding(10),
+                    ].spacing(10).align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else if self.show_about {
+            modal_overlay(main_content.into(), self.view_about())
+        } else if !self.is_loading && self.settings.last_seen_version != APP_VERSION {
+            modal_overlay(
+                main_content.into(),
+                column![
+                    text(format!("What's new in {}", APP_VERSION)).size(24),
+                    Scrollable::new(text(CHANGELOG)).height(Length::Fixed(300.0)),
+                    Button::new(text("Got it"))
+                        .on_press(Message::ChangelogDismissed)
+                        .padding(10),
+                ]
+                .spacing(10)
+                .width(Length::Fixed(600.0))
+                .align_items(Alignment::Center)
+                .into(),
+            )
+        } else {
+            main_content.into()
+        }
+    }
+}
+
+impl App {
+    /// Whether destructive actions are disabled, either by setting or the `--read-only` flag.
+    /// Replayed snapshots count as read-only: their PIDs may belong to other processes by now.
+    fn is_read_only(&self) -> bool {
+        self.read_only_forced || self.settings.read_only || self.is_replaying
+    }
+
+    /// The running process behind `pid`, except while replaying: snapshot PIDs may belong to
+    /// a different program by now, so replay only trusts `replayed_row`.
+    fn live_process(&self, pid: Pid) -> Option<&sysinfo::Process> {
+        self.system.process(pid).filter(|_| !self.is_replaying)
+    }
+
+    /// The snapshot's row for `pid` while replaying.
+    fn replayed_row(&self, pid: Pid) -> Option<&ProcessData> {
+        detail_row(&self.process_list, pid).filter(|_| self.is_replaying)
+    }
+
+    /// Shows `status` and clears it once `delay` has passed, unless a newer message has
+    /// replaced it by then; that one (and a relaunch offer shown with it) keeps its full time.
+    fn show_status(&mut self, status: StatusMessage, delay: Duration) -> Command<Message> {
+        self.last_status_id += 1;
+        let id = self.last_status_id;
+        self.last_status_message = Some(StatusMessage { id, ..status });
+        Command::perform(tokio::time::sleep(delay), move |_| Message::ClearStatusMessage(id))
+    }
+
+    /// Status feedback for a destructive action that was ignored because of read-only mode.
+    fn read_only_blocked(&mut self) -> Command<Message> {
+        tracing::warn!("Destructive action ignored: read-only mode is on");
+        self.show_status(StatusMessage::error("Read-only mode is on: action disabled 🔒"), Duration::from_secs(3))
+    }
+
+    /// Status text tinted by `status_color`, keeping the label for accessibility.
+    fn status_label(&self, status: ProcessStatus) -> Element<'static, Message, Theme, Renderer> {
+        let label = text(status.to_string());
+        match status_color(status, self.effective_theme() == ThemeChoice::Dark) {
+            Some(color) => label.style(color).into(),
+            None => label.into(),
+        }
+    }
+
+    fn tr(&self, key: UiText) -> &'static str {
+        key.text(self.settings.language)
+    }
+
+    fn sizing(&self) -> Sizing {
+        if self.settings.compact_mode { Sizing::COMPACT } else { Sizing::NORMAL }
+    }
+
+    fn format_number(&self, value: f64, decimals: usize) -> String {
+        format_grouped(value, decimals, self.settings.number_grouping)
+    }
+
+    fn format_memory(&self, bytes: u64) -> String {
+        format_memory(bytes, self.settings.memory_unit, self.settings.number_grouping)
+    }
+
+    fn select_process(&mut self, pid: Pid) {
+        if self.selection_baseline.map(|b| b.pid) != Some(pid) {
+            self.selection_baseline = self.live_process(pid).map(|p| SelectionBaseline {
+                pid,
+                selected_at: Instant::now(),
+                cpu_usage: cap_cpu(p.cpu_usage(), self.cpu_cap()),
+                memory: p.memory(),
+            });
+        }
+        self.selected_process = Some(pid);
+        self.selected_process_name = match self.replayed_row(pid) {
+            Some(row) => Some(row.name.clone()),
+            None => self.system.process(pid).map(|p| p.name().to_string()),
+        };
+        // Both would read whichever live process now has the snapshot's PID.
+        let live = !self.is_replaying;
+        self.selected_fd_count = Some((pid, live.then(|| open_fd_count(pid)).flatten()));
+        self.selected_nice = Some((pid, live.then(|| process_nice(pid).ok()).flatten()));
+    }
+
+    /// How often the metrics timer fires, from the refresh interval setting.
+    fn metrics_interval(&self) -> Duration {
+        Duration::from_secs(self.settings.refresh_interval_secs.max(1))
+    }
+
+    /// The process refresh interval, which may have backed off but never outpaces metrics.
+    fn process_interval(&self) -> Duration {
+        self.rebuild_timing.interval.max(self.metrics_interval())
+    }
+
+    /// Whether the main window is minimized and background sampling should skip heavy work.
+    fn is_background_sampling(&self) -> bool {
+        self.settings.background_sampling && self.minimized
+    }
+
+    /// Jumps the displayed card values straight to the latest reading.
+    fn snap_card_values(&mut self) {
+        self.displayed_cpu_usage = self.dashboard_data.cpu_usage as f64;
+        self.displayed_memory_used = self.dashboard_data.memory_used as f64;
+    }
+
+    /// Whether the displayed card values are still easing toward the latest reading.
+    fn card_values_animating(&self) -> bool {
+        self.displayed_cpu_usage != self.dashboard_data.cpu_usage as f64
+            || self.displayed_memory_used != self.dashboard_data.memory_used as f64
+    }
+
+    /// Re-samples global CPU and memory without touching per-process data.
+    fn refresh_metrics(&mut self) {
+        if self.is_replaying {
+            return;
+        }
+        self.system.refresh_cpu();
+        self.system.refresh_memory();
+        let (memory_used, memory_metric) = self.settings.memory_metric.read(&self.system);
+        self.dashboard_data = SystemData {
+            cpu_usage: self.system.global_cpu_info().cpu_usage(),
+            memory_used,
+            memory_metric,
+            memory_other: memory_metric.read_other(&self.system),
+            memory_total: self.system.total_memory(),
+            swap_used: self.system.used_swap(),
+            swap_total: self.system.total_swap(),
+            per_core_usage: self.system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+            per_core_frequency: self.system.cpus().iter().map(|cpu| cpu.frequency()).collect(),
+            // Fixed for the machine, so read once at startup.
+            max_frequency: self.dashboard_data.max_frequency,
+            uptime_secs: System::uptime(),
+            boot_time: System::boot_time(),
+            load_average: read_load_average(),
+            process_count: self.dashboard_data.process_count,
+        };
+        let mem_percent = self.dashboard_data.memory_percent();
+        let swap_percent = self.dashboard_data.swap_percent();
+        push_sample(&mut self.cpu_history, self.dashboard_data.cpu_usage);
+        push_sample(&mut self.mem_history, mem_percent);
+        push_sample(&mut self.swap_history, swap_percent);
+        let cpus = self.system.cpus();
+        if self.per_core_history.len() != cpus.len() {
+            self.per_core_history.resize_with(cpus.len(), || VecDeque::with_capacity(HISTORY_LEN));
+        }
+        for (history, cpu) in self.per_core_history.iter_mut().zip(cpus) {
+            push_sample(history, cpu.cpu_usage());
+        }
+    }
+
+    /// Re-reads interface counters and turns the change since the last refresh into rates.
+    /// `refresh_list` picks up interfaces that appeared and drops ones that went away.
+    fn refresh_networks(&mut self) {
+        self.networks.refresh_list();
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_network_refresh).as_secs_f64();
+        self.last_network_refresh = now;
+        let per_sec = |bytes: u64| if elapsed > 0.0 { bytes as f64 / elapsed } else { 0.0 };
+        let mut network_data: Vec<NetworkData> = self.networks.list().iter()
+            .map(|(name, network)| NetworkData {
+                name: name.clone(),
+                rx_per_sec: per_sec(network.received()),
+                tx_per_sec: per_sec(network.transmitted()),
+                total_rx: network.total_received(),
+                total_tx: network.total_transmitted(),
+            })
+            .collect();
+        network_data.sort_by(|a, b| a.name.cmp(&b.name));
+        self.network_data = network_data;
+    }
+
+    #[cfg(feature = "gpu")]
+    fn refresh_gpus(&mut self) {
+        self.gpu_data = self.nvml.as_ref().map_or_else(Vec::new, read_gpus);
+    }
+
+    #[cfg(not(feature = "gpu"))]
+    fn refresh_gpus(&mut self) {}
+
+    fn refresh_sensors(&mut self) {
+        self.components.refresh();
+        self.sensor_data = SensorData::read_all(&self.components);
+    }
+
+    /// Rebuilds the disk snapshot from scratch, so drives unplugged since the last refresh
+    /// simply drop out of the table. Only done while the Disks tab is open, since network
+    /// mounts can make this slow.
+    fn refresh_disks(&mut self) {
+        if self.is_replaying {
+            return;
+        }
+        self.disks.refresh_list();
+        self.disk_data = self.disks.list().iter()
+            .map(|disk| DiskData {
+                name: disk.name().to_string_lossy().into_owned(),
+                mount_point: disk.mount_point().display().to_string(),
+                file_system: disk.file_system().to_string_lossy().into_owned(),
+                total: disk.total_space(),
+                available: disk.available_space(),
+                removable: disk.is_removable(),
+            })
+            .collect();
+        self.disk_data.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    }
+
+    /// Re-samples the process table without re-sampling global CPU metrics.
+    ///
+    /// Only what the UI reads is refreshed, and the executable, command line and user are
+    /// read once per process instead of on every pass. Rows of processes seen last time are
+    /// updated in place, so text is only built for new processes. Each pass is timed into
+    /// `rebuild_timing`, which the diagnostics report shows. Swap is read separately by
+    /// `refresh_swap`.
+    fn refresh_processes(&mut self) {
+        if self.is_replaying {
+            return;
+        }
+        let started = Instant::now();
+        self.system.refresh_processes_specifics(
+            ProcessRefreshKind::new()
+                .with_cpu()
+                .with_memory()
+                .with_disk_usage()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet)
+                .with_user(UpdateKind::OnlyIfNotSet),
+        );
+        self.dashboard_data.process_count = self.system.processes().len();
+        self.smoothed_process_count += PROCESS_COUNT_SMOOTHING
+            * (self.dashboard_data.process_count as f64 - self.smoothed_process_count);
+        let (mut process_list, gone) =
+            App::build_process_list(std::mem::take(&mut self.process_list), &self.system, &self.users, self.cpu_cap());
+        let elapsed = started.duration_since(self.last_process_refresh).as_secs_f64();
+        self.last_process_refresh = started;
+        let totals = disk_io_totals(&self.system);
+        for process in &mut process_list {
+            let Some(&(read, written)) = totals.get(&process.pid) else { continue };
+            let previous = self.disk_io_totals.get(&process.pid);
+            process.disk_read_rate = disk_rate(previous.map(|&(read, _)| read), read, elapsed);
+            process.disk_write_rate = disk_rate(previous.map(|&(_, written)| written), written, elapsed);
+        }
+        self.disk_io_totals = totals;
+        self.cpu_by_user = aggregate_cpu_by_user(&process_list, CPU_BY_USER_TOP);
+        self.top_memory = top_by_memory(&process_list, TOP_MEMORY_COUNT);
+        self.status_counts = count_by_status(&process_list);
+        self.process_users = distinct_users(&process_list);
+        let live: std::collections::HashSet<Pid> = process_list.iter().map(|p| p.pid).collect();
+        let timestamp = chrono::Local::now().format("%H:%M:%S");
+        let events = process_lifecycle_events(&self.previous_pids, &gone, &process_list, self.settings.language);
+        for event in events {
+            self.event_log.push_back(format!("[{}] {}", timestamp, event));
+        }
+        while self.event_log.len() > EVENT_LOG_CAPACITY {
+            self.event_log.pop_front();
+        }
+        self.previous_pids = live.clone();
+        self.session_averages.retain(|pid, _| live.contains(pid));
+        for process in &process_list {
+            self.session_averages.entry(process.pid).or_default().record(process.cpu_usage, process.memory);
+        }
+        let grace = Duration::from_millis(self.settings.process_grace_period_ms);
+        App::retain_exited_rows(gone, &mut process_list, grace);
+        self.process_list = process_list;
+        self.refresh_swap();
+        let is_listed = |pid: Pid, list: &[ProcessData]| list.iter().any(|p| p.pid == pid);
+        if let Some(pid) = self.selected_process {
+            if !selection_is_current(&self.process_list, pid, self.selected_process_name.as_deref()) {
+                self.selected_process = None;
+                self.selected_process_name = None;
+                self.selection_baseline = None;
+                if self.compare_process.is_some_and(|pinned| pinned != pid) {
+                    self.compare_process = None;
+                }
+            }
+        }
+        self.selected_fd_count = self.selected_process.map(|pid| (pid, open_fd_count(pid)));
+        self.selected_nice = self.selected_process.map(|pid| (pid, process_nice(pid).ok()));
+        if let Some(pid) = self.compare_process {
+            if self.system.process(pid).is_none() {
+                self.compare_process = None;
+            }
+        }
+        if let Some(pid) = self.hovered_process {
+            if !is_listed(pid, &self.process_list) {
+                self.hovered_process = None;
+                self.tooltip_visible = false;
+            }
+        }
+        self.rebuild_timing.record(started.elapsed());
+    }
+
+    /// Slows the process refresh down when the last rebuild didn't fit comfortably in the
+    /// interval, warning the user the first time it happens.
+    fn back_off_if_slow(&mut self) -> Command<Message> {
+        let Some(last) = self.rebuild_timing.last else {
+            return Command::none();
+        };
+        let Some(interval) = backed_off_interval(self.rebuild_timing.interval, last) else {
+            return Command::none();
+        };
+        tracing::warn!(
+            "Process rebuild took {} ms; slowing refresh from {}s to {}s",
+            last.as_millis(),
+            self.rebuild_timing.interval.as_secs(),
+            interval.as_secs(),
+        );
+        self.rebuild_timing.interval = interval;
+        if std::mem::replace(&mut self.rebuild_timing.warned, true) {
+            return Command::none();
+        }
+        let warning = format!(
+            "Process list is slow to build ({} ms); refreshing every {}s instead ⚠️",
+            last.as_millis(),
+            interval.as_secs(),
+        );
+        self.show_status(StatusMessage::error(&warning), Duration::from_secs(5))
+    }
+
+    /// Checks every auto rule against the fresh process list, firing rules whose condition
+    /// has held for their full duration. Each (rule, PID) fires at most once per breach.
+    fn evaluate_auto_rules(&mut self) -> Command<Message> {
+        if !self.settings.auto_rules_enabled || self.settings.auto_rules.is_empty() {
+            self.rule_breaches.clear();
+            return Command::none();
+        }
+
+        let now = Instant::now();
+        let mut breaching = std::collections::HashSet::new();
+        let mut fired = Vec::new();
+        for (index, rule) in self.settings.auto_rules.iter().enumerate() {
+            let pattern = rule.name_pattern.to_lowercase();
+            for process in self.process_list.iter().filter(|p| p.exited_at.is_none()) {
+                if !process.name.to_lowercase().contains(&pattern) {
+                    continue;
+                }
+                let value = match rule.metric {
+                    RuleMetric::Cpu => process.cpu_usage as f64,
+                    RuleMetric::Memory => process.memory as f64 / (1024.0 * 1024.0),
+                };
+                if value <= rule.threshold {
+                    continue;
+                }
+                let key = (index, process.pid);
+                breaching.insert(key);
+                let breach = self.rule_breaches.entry(key).or_insert(RuleBreach { since: now, fired: false });
+                if !breach.fired && now.duration_since(breach.since) >= Duration::from_secs(rule.duration_secs) {
+                    breach.fired = true;
+                    fired.push((rule.clone(), process.pid, process.name.clone(), value));
+                }
+            }
+        }
+        self.rule_breaches.retain(|key, _| breaching.contains(key));
+
+        if fired.is_empty() {
+            return Command::none();
+        }
+        let mut outcomes = Vec::new();
+        for (rule, pid, name, value) in fired {
+            let label = self.process_label(pid);
+            let outcome = if rule.action == RuleAction::Kill && self.is_read_only() {
+                tracing::warn!("Auto rule [{}] matched {} ({}) at {:.1}, kill skipped (read-only)", rule.describe(), name, pid, value);
+                format!("Rule matched {}, kill skipped: read-only 🔒", label)
+            } else if rule.action == RuleAction::Kill {
+                let result = self.system.process(pid)
+                    .map_or(Err("no such process".to_string()), kill_process);
+                match result {
+                    Ok(()) => {
+                        self.process_list.retain(|p| p.pid != pid);
+                        tracing::warn!("Auto rule [{}] killed {} ({}) at {:.1}", rule.describe(), name, pid, value);
+                        format!("Rule killed {} ✅", label)
+                    }
+                    Err(reason) => {
+                        tracing::error!("Auto rule [{}] failed to kill {} ({}) at {:.1}: {}", rule.describe(), name, pid, value, reason);
+                        format!("Rule failed to kill {}: {} ⚠️", label, reason)
+                    }
+                }
+            } else {
+                tracing::warn!("Auto rule [{}] alert for {} ({}) at {:.1}", rule.describe(), name, pid, value);
+                format!("Rule alert: {} {}", label, rule.describe())
+            };
+            outcomes.push(outcome);
+        }
+        let summary = match outcomes.len() {
+            1 => outcomes.remove(0),
+            n => format!("{} (+{} more rule actions)", outcomes[n - 1], n - 1),
+        };
+        self.show_status(StatusMessage::error(&summary), Duration::from_secs(5))
+    }
+
+    /// Upper bound for per-process CPU readings, `None` when capping is turned off.
+    /// Some platforms briefly report impossible values (thousands of percent) right after a
+    /// process starts or when samples are taken too close together; the cap keeps those from
+    /// dominating the table and the sort order.
+    fn cpu_cap(&self) -> Option<f32> {
+        self.settings.cap_process_cpu.then(|| self.system.cpus().len().max(1) as f32 * 100.0)
+    }
+
+    /// Re-reads per-process swap, which costs a `/proc/<pid>/status` read each, only where it
+    /// is shown: the table rows on screen (every filtered row when sorting by swap), and on
+    /// the dashboard a full scan for the top swappers at most every `SWAP_SCAN_INTERVAL`.
+    fn refresh_swap(&mut self) {
+        if self.is_background_sampling() {
+            return;
+        }
+        let pids: Vec<Pid> = match self.active_tab {
+            Tab::Processes if self.settings.visible_columns.contains(&SortColumn::Swap) => {
+                let mut rows = self.visible_processes();
+                if self.sort_column != SortColumn::Swap && self.settings.grouping_mode == GroupingMode::None {
+                    rows.truncate(self.settings.process_row_limit);
+                }
+                rows.iter().map(|p| p.pid).collect()
+            }
+            Tab::Dashboard if self.last_swap_scan.is_none_or(|scan| scan.elapsed() >= SWAP_SCAN_INTERVAL) => {
+                self.last_swap_scan = Some(Instant::now());
+                self.process_list.iter().map(|p| p.pid).collect()
+            }
+            _ => return,
+        };
+        let swap: HashMap<Pid, Option<u64>> = pids.into_iter().map(|pid| (pid, read_process_swap(pid))).collect();
+        for process in &mut self.process_list {
+            if let Some(&value) = swap.get(&process.pid) {
+                process.swap = value;
+            }
+        }
+    }
+
+    /// A row per running process, sorted, plus the `previous` rows whose PID isn't running.
+    ///
+    /// A previous row with the same PID and start time is the same process, so only its
+    /// changing fields are updated and its swap reading is kept; a row is built from scratch
+    /// only for new processes, whose swap is left for `refresh_swap` to fill in.
+    fn build_process_list(
+        previous: Vec<ProcessData>,
+        sys: &System,
+        users: &Users,
+        cpu_cap: Option<f32>,
+    ) -> (Vec<ProcessData>, Vec<ProcessData>) {
+        let mut known: HashMap<Pid, ProcessData> = previous.into_iter().map(|row| (row.pid, row)).collect();
+        let mut processes: Vec<ProcessData> = sys
+            .processes()
+            .values()
+            .map(|p| match known.remove(&p.pid()).filter(|row| row.start_time == p.start_time()) {
+                Some(mut row) => {
+                    if row.name != p.name() {
+                        row.name = p.name().to_string();
+                    }
+                    row.cpu_usage = cap_cpu(p.cpu_usage(), cpu_cap);
+                    row.memory = p.memory();
+                    row.status = p.status();
+                    row.parent = p.parent();
+                    row.exited_at = None;
+                    row.disk_read_rate = 0.0;
+                    row.disk_write_rate = 0.0;
+                    row.run_time = (p.start_time() > 0).then(|| p.run_time());
+                    row
+                }
+                None => ProcessData {
+                    pid: p.pid(),
+                    name: p.name().to_string(),
+                    cpu_usage: cap_cpu(p.cpu_usage(), cpu_cap),
+                    memory: p.memory(),
+                    swap: None,
+                    status: p.status(),
+                    exe: p.exe().map(|path| path.to_string_lossy().to_string()),
+                    cmd: p.cmd().join(" "),
+                    user: p.user_id()
+                        .and_then(|uid| users.get_user_by_id(uid))
+                        .map(|user| user.name().to_string()),
+                    parent: p.parent(),
+                    exited_at: None,
+                    disk_read_rate: 0.0,
+                    disk_write_rate: 0.0,
+                    run_time: (p.start_time() > 0).then(|| p.run_time()),
+                    start_time: p.start_time(),
+                },
+            })
+            .collect();
+        App::sort_processes(&mut processes);
+        (processes, known.into_values().collect())
+    }
+
+    fn sort_processes(processes: &mut [ProcessData]) {
+        processes.sort_by(|a, b| {
+            b.cpu_usage
+                .partial_cmp(&a.cpu_usage)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Carries rows whose PID vanished over into `current` until the grace period expires,
+    /// so short-lived processes stay visible instead of flickering in and out.
+    fn retain_exited_rows(previous: Vec<ProcessData>, current: &mut Vec<ProcessData>, grace: Duration) {
+        if grace.is_zero() {
+            return;
+        }
+        let live: std::collections::HashSet<Pid> = current.iter().map(|p| p.pid).collect();
+        let now = Instant::now();
+        current.extend(previous.into_iter().filter(|p| !live.contains(&p.pid)).filter_map(|mut p| {
+            let exited_at = *p.exited_at.get_or_insert(now);
+            (now.duration_since(exited_at) < grace).then_some(p)
+        }));
+        App::sort_processes(current);
+    }
+
+    /// Rows for CSV/JSON exports; rows kept only for the churn grace period are left out.
+    fn export_rows(&self) -> Vec<ProcessExportData> {
+        self.process_list.iter()
+            .filter(|p| p.exited_at.is_none())
+            .map(|p| ProcessExportData {
+                pid: p.pid.as_u32() as usize,
+                name: p.name.clone(),
+                cpu_usage: p.cpu_usage,
+                memory_bytes: p.memory,
+            })
+            .collect()
+    }
+
+    fn auto_export_dir(&self) -> PathBuf {
+        if self.settings.auto_export_dir.trim().is_empty() {
+            data_dir()
+                .map(|dir| dir.join("exports"))
+                .unwrap_or_else(|| PathBuf::from("exports"))
+        } else {
+            PathBuf::from(self.settings.auto_export_dir.trim())
+        }
+    }
+
+    /// Feeds the latest system CPU and memory readings to the usage alerts, notifying the
+    /// desktop and the status bar when one fires.
+    fn check_usage_alerts(&mut self) -> Command<Message> {
+        if !self.settings.usage_alerts_enabled {
+            return Command::none();
+        }
+        let now = Instant::now();
+        let data = &self.dashboard_data;
+        let mem_percent = data.memory_percent();
+        let mut alerts = Vec::new();
+        if self.cpu_alert.observe(data.cpu_usage, self.settings.cpu_alert_threshold, now) {
+            alerts.push(format!("CPU usage has stayed above {:.0}% (now {:.0}%)", self.settings.cpu_alert_threshold, data.cpu_usage));
+        }
+        if self.mem_alert.observe(mem_percent, self.settings.mem_alert_threshold, now) {
+            alerts.push(format!("Memory usage has stayed above {:.0}% (now {:.0}%)", self.settings.mem_alert_threshold, mem_percent));
+        }
+        if alerts.is_empty() {
+            return Command::none();
+        }
+        let body = alerts.join("\n");
+        tracing::warn!("Usage alert: {}", body.replace('\n', "; "));
+        show_desktop_notification("System Monitor", &body);
+        self.show_status(StatusMessage::error(&format!("{} ⚠️", alerts.join(" · "))), Duration::from_secs(5))
+    }
+
+    /// Current CPU and memory of the process a kill/signal dialog is about to act on.
+    fn confirm_target_usage(&self, pid: Pid) -> String {
+        match self.system.process(pid) {
+            Some(process) => format!(
+                "CPU: {:.1} % · Memory: {}",
+                cap_cpu(process.cpu_usage(), self.cpu_cap()),
+                self.format_memory(process.memory()),
+            ),
+            None => "This process has already exited.".to_string(),
+        }
+    }
+
+    /// Closes a kill, signal or restart confirmation whose target exited while it was open.
+    fn dismiss_stale_confirmation(&mut self) -> Command<Message> {
+        let target = self.show_kill_confirm
+            .or(self.show_signal_confirm.map(|(pid, _)| pid))
+            .or(self.show_restart_confirm);
+        let Some(pid) = target.filter(|pid| self.system.process(*pid).is_none()) else {
+            return Command::none();
+        };
+        self.show_kill_confirm = None;
+        self.show_signal_confirm = None;
+        self.show_restart_confirm = None;
+        let err_msg = format!("Process {} exited before it could be confirmed ⚠️", pid);
+        self.show_status(StatusMessage::error(&err_msg), Duration::from_secs(3))
+    }
+
+    /// Everything the metrics timer does on each tick.
+    fn metrics_tick(&mut self) -> Command<Message> {
+        self.refresh_metrics();
+        let alerts = self.check_usage_alerts();
+        self.refresh_networks();
+        if self.active_tab == Tab::Disks {
+            self.refresh_disks();
+        }
+        if self.active_tab == Tab::Dashboard && !self.minimized {
+            self.refresh_sensors();
+            self.refresh_gpus();
+        }
+        self.update_metrics_snapshot();
+        if self.settings.reduce_motion || self.active_tab != Tab::Dashboard || self.minimized {
+            self.snap_card_values();
+        }
+        if self.settings.background_sampling {
+            Command::batch([alerts, window::fetch_minimized(window::Id::MAIN, Message::MinimizedChecked)])
+        } else {
+            alerts
+        }
+    }
+
+    /// Everything the process timer does on each tick.
+    fn processes_tick(&mut self) -> Command<Message> {
+        let logged = self.event_log.len();
+        self.refresh_processes();
+        let back_off = self.back_off_if_slow();
+        let dismissed = self.dismiss_stale_confirmation();
+        // Keep the newest events in view while the pane is open.
+        let follow_log = if self.show_event_log && self.event_log.len() != logged {
+            scrollable::snap_to(event_log_id(), scrollable::RelativeOffset::END)
+        } else {
+            Command::none()
+        };
+        Command::batch([back_off, dismissed, follow_log, self.evaluate_auto_rules()])
+    }
+
+    /// Swaps the live data for a loaded snapshot. The usage histories are cleared so the
+    /// charts don't mix live samples with the snapshot's; they refill once replay ends.
+    fn start_replay(&mut self, path: &str, snapshot: SystemSnapshot) {
+        let file_name = std::path::Path::new(path)
+            .file_name()
+            .map_or_else(|| path.to_string(), |name| name.to_string_lossy().into_owned());
+        let taken = chrono::DateTime::parse_from_rfc3339(&snapshot.timestamp)
+            .map_or(snapshot.timestamp.clone(), |time| time.format("%Y-%m-%d %H:%M:%S").to_string());
+        self.replay_info = format!("Replaying {} from {} (v{})", file_name, taken, snapshot.app_version);
+        self.is_replaying = true;
+        self.relaunch_offer = None;
+        self.dashboard_data = snapshot.system;
+        self.network_data = snapshot.networks;
+        self.disk_data = snapshot.disks;
+        self.cpu_history.clear();
+        self.mem_history.clear();
+        self.swap_history.clear();
+        self.per_core_history.clear();
+        let mut process_list: Vec<ProcessData> =
+            snapshot.processes.into_iter().map(SnapshotProcess::restore).collect();
+        App::sort_processes(&mut process_list);
+        self.cpu_by_user = aggregate_cpu_by_user(&process_list, CPU_BY_USER_TOP);
+        self.top_memory = top_by_memory(&process_list, TOP_MEMORY_COUNT);
+        self.status_counts = count_by_status(&process_list);
+        self.process_users = distinct_users(&process_list);
+        self.process_list = process_list;
+        self.selected_process = None;
+        self.selected_process_name = None;
+        self.selection_baseline = None;
+        self.compare_process = None;
+        self.hovered_process = None;
+        self.tooltip_visible = false;
+        self.snap_card_values();
+    }
+
+    /// One full refresh, exactly as the two timers would do it, for manual refreshes.
+    fn refresh(&mut self) -> Command<Message> {
+        let metrics = self.metrics_tick();
+        let processes = self.processes_tick();
+        self.snap_card_values();
+        Command::batch([metrics, processes])
+    }
+
+    /// One-off refresh after acting on a process while paused, so the frozen view doesn't
+    /// keep showing a process that was just killed.
+    fn refresh_if_paused(&mut self) {
+        if self.is_paused {
+            self.refresh_metrics();
+            self.refresh_processes();
+            self.snap_card_values();
+        }
+    }
+
+    fn effective_theme(&self) -> ThemeChoice {
+        self.settings.theme.resolve(self.system_dark)
+    }
+
+    #[cfg(feature = "tray")]
+    fn has_tray(&self) -> bool {
+        self.tray.is_some()
+    }
+
+    #[cfg(not(feature = "tray"))]
+    fn has_tray(&self) -> bool {
+        false
+    }
+
+    /// Whether closing the main window hides it to the tray instead of quitting.
+    fn hides_to_tray(&self) -> bool {
+        self.settings.start_in_tray && self.has_tray()
+    }
+
+    fn set_window_hidden(&mut self, hidden: bool) -> Command<Message> {
+        self.window_hidden = hidden;
+        if hidden {
+            window::change_mode(window::Id::MAIN, window::Mode::Hidden)
+        } else {
+            Command::batch([
+                window::change_mode(window::Id::MAIN, window::Mode::Windowed),
+                window::gain_focus(window::Id::MAIN),
+            ])
+        }
+    }
+
+    /// Brings back a window that started hidden when there is no tray icon to restore it from.
+    fn show_if_stranded(&mut self) -> Command<Message> {
+        if self.window_hidden && !self.hides_to_tray() {
+            self.set_window_hidden(false)
+        } else {
+            Command::none()
+        }
+    }
+
+    /// Writes any pending settings and closes every window, which ends the app.
+    fn quit(&mut self) -> Command<Message> {
+        // A save still waiting out its debounce is written right here instead.
+        if self.pending_save.take() {
+            if let Err(e) = self.settings.save_now() {
+                tracing::error!("Failed to save settings on exit: {}", e);
+            }
+        }
+        // The runtime only exits once every window is gone.
+        let graphs: Vec<window::Id> = self.graph_windows.drain().map(|(id, _)| id).collect();
+        Command::batch(graphs.into_iter().chain([window::Id::MAIN]).map(window::close))
+    }
+
+    /// Acts on tray clicks and menu picks made since the last poll. A left click on the icon
+    /// toggles the window like the menu entry does (Linux trays only report menu picks).
+    #[cfg(feature = "tray")]
+    fn handle_tray_events(&mut self) -> Command<Message> {
+        use tray_icon::{menu::MenuEvent, MouseButton, MouseButtonState, TrayIconEvent};
+        let mut toggles = TrayIconEvent::receiver()
+            .try_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    TrayIconEvent::Click { button: MouseButton::Left, button_state: MouseButtonState::Up, .. }
+                )
+            })
+            .count();
+        for event in MenuEvent::receiver().try_iter() {
+            if event.id == TRAY_QUIT_ID {
+                return self.quit();
+            }
+            if event.id == TRAY_TOGGLE_ID {
+                toggles += 1;
+            }
+        }
+        if toggles % 2 == 1 {
+            self.set_window_hidden(!self.window_hidden)
+        } else {
+            Command::none()
+        }
+    }
+
+    #[cfg(not(feature = "tray"))]
+    fn handle_tray_events(&mut self) -> Command<Message> {
+        Command::none()
+    }
+
+    /// Marks settings as changed and schedules a write once they've been quiet for
+    /// `SETTINGS_SAVE_DEBOUNCE`, so dragging a slider doesn't write on every step.
+    fn schedule_save(&mut self) -> Command<Message> {
+        let generation = self.pending_save.mark();
+        Command::perform(tokio::time::sleep(SETTINGS_SAVE_DEBOUNCE), move |_| Message::FlushSettings(generation))
+    }
+
+    fn confirmation_open(&self) -> bool {
+        self.show_kill_confirm.is_some()
+            || self.show_bulk_kill_confirm.is_some()
+            || self.show_signal_confirm.is_some()
+            || self.show_restart_confirm.is_some()
+            || self.show_auto_rules_confirm
+    }
+
+    /// Built-in presets followed by the user's saved profiles.
+    fn all_profiles(&self) -> Vec<SettingsProfile> {
+        let mut profiles = SettingsProfile::built_in();
+        profiles.extend(self.settings.profiles.iter().cloned());
+        profiles
+    }
+
+    /// The active profile's name when it is a user profile (built-ins are read-only).
+    fn active_user_profile(&self) -> Option<String> {
+        self.settings.active_profile.clone()
+            .filter(|name| self.settings.profiles.iter().any(|p| &p.name == name))
+    }
+
+    /// Rejects empty names and names that would clash with a built-in or another profile.
+    /// `renaming` is the profile being renamed, which may keep its own name.
+    fn check_profile_name(&self, name: &str, renaming: Option<&str>) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("enter a profile name".to_string());
+        }
+        if SettingsProfile::built_in().iter().any(|p| p.name == name) {
+            return Err(format!("\"{}\" is a built-in profile", name));
+        }
+        if renaming.is_some_and(|old| old != name) && self.settings.profiles.iter().any(|p| p.name == name) {
+            return Err(format!("a profile named \"{}\" already exists", name));
+        }
+        Ok(())
+    }
+
+    fn profile_error(&mut self, reason: &str) -> Command<Message> {
+        self.show_status(StatusMessage::error(&format!("Profile not changed: {} ⚠️", reason)), Duration::from_secs(3))
+    }
+
+    /// Starts, stops or moves the metrics server so it matches the current settings.
+    fn sync_metrics_server(&mut self) -> Command<Message> {
+        let wanted = self.settings.metrics_endpoint_enabled.then_some(self.settings.metrics_port);
+        if self.metrics_server.as_ref().map(|(port, _)| *port) == wanted {
+            return Command::none();
+        }
+        // Dropping the sender tells a running server to shut down.
+        self.metrics_server = None;
+        let Some(port) = wanted else {
+            return Command::none();
+        };
+        self.update_metrics_snapshot();
+        let (shutdown, shutdown_rx) = tokio::sync::watch::channel(());
+        self.metrics_server = Some((port, shutdown));
+        Command::perform(
+            serve_metrics(port, self.metrics_snapshot.clone(), shutdown_rx),
+            move |result| Message::MetricsServerStopped(port, result),
+        )
+    }
+
+    fn update_metrics_snapshot(&self) {
+        if self.metrics_server.is_none() {
+            return;
+        }
+        let data = &self.dashboard_data;
+        if let Ok(mut snapshot) = self.metrics_snapshot.write() {
+            *snapshot = MetricsSnapshot {
+                cpu_usage_percent: data.cpu_usage,
+                memory_used_bytes: data.memory_used,
+                memory_total_bytes: data.memory_total,
+                swap_used_bytes: data.swap_used,
+                swap_total_bytes: data.swap_total,
+                process_count: data.process_count,
+            };
+        }
+    }
+
+    fn prune_exports_command(&self) -> Command<Message> {
+        Command::perform(
+            App::prune_exports(
+                self.auto_export_dir(),
+                self.settings.export_retention_max_files,
+                self.settings.export_retention_max_age_days,
+            ),
+            Message::ExportsPruned,
+        )
+    }
+
+    /// Deletes scheduled export files past the retention limits. Only files matching the
+    /// scheduled export naming scheme are considered, so anything else in the folder is safe.
+    async fn prune_exports(dir: PathBuf, max_files: usize, max_age_days: u64) -> Result<usize, String> {
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.to_string()),
+        };
+        let mut names = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
+            if let Some(name) = entry.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+        let max_age = chrono::Duration::days(max_age_days as i64);
+        let now = chrono::Local::now().naive_local();
+        let mut pruned = 0;
+        for name in exports_to_prune(&names, max_files, max_age, now) {
+            match tokio::fs::remove_file(dir.join(&name)).await {
+                Ok(()) => pruned += 1,
+                Err(e) => tracing::warn!("Failed to delete old export {}: {}", name, e),
+            }
+        }
+        Ok(pruned)
+    }
+
+    async fn save_export(
+        process_list: Vec<ProcessExportData>,
+        path_buf: PathBuf,
+        format: ExportFormat,
+    ) -> Result<String, String> {
+        let path_str = path_buf.to_string_lossy().to_string();
+
+        if let Some(parent) = path_buf.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        match format {
+            ExportFormat::Csv => {
+                tokio::task::spawn_blocking(move || -> Result<(), String> {
+                    let mut wtr = csv::Writer::from_path(path_buf)
+                        .map_err(|e| e.to_string())?;
+                    for process in process_list {
+                        wtr.serialize(process)
+                            .map_err(|e| e.to_string())?;
+                    }
+                    wtr.flush().map_err(|e| e.to_string())
+                })
+                .await
+                .map_err(|e| e.to_string())??;
+            }
+            ExportFormat::Json => {
+                let content = serde_json::to_string_pretty(&process_list).map_err(|e| e.to_string())?;
+                tokio::fs::write(path_buf, content)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(path_str)
+    }
+
+    async fn save_snapshot(snapshot: SystemSnapshot) -> Result<String, String> {
+        let file_name = format!("snapshot_{}.json", chrono::Local::now().format(AUTO_EXPORT_TIMESTAMP_FORMAT));
+        let path_buf = data_dir().map_or_else(|| PathBuf::from(&file_name), |dir| dir.join(&file_name));
+        let path_str = path_buf.to_string_lossy().to_string();
+        let content = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+        tokio::task::spawn_blocking(move || write_creating_dirs(&path_buf, &content))
+            .await
+            .map_err(|e| e.to_string())??;
+        Ok(path_str)
+    }
+
+    /// Reads a saved snapshot, defaulting to the newest one "Save Snapshot" wrote.
+    async fn load_snapshot(path: Option<PathBuf>) -> Result<(String, Box<SystemSnapshot>), String> {
+        let path_buf = match path {
+            Some(path) => path,
+            None => {
+                let dir = data_dir().ok_or("no data folder")?;
+                let mut entries = tokio::fs::read_dir(&dir).await.map_err(|e| e.to_string())?;
+                let mut names = Vec::new();
+                while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
+                    if let Some(name) = entry.file_name().to_str() {
+                        names.push(name.to_string());
+                    }
+                }
+                let newest = newest_snapshot(&names).ok_or("no saved snapshots")?;
+                dir.join(newest)
+            }
+        };
+        let content = tokio::fs::read_to_string(&path_buf).await.map_err(|e| e.to_string())?;
+        let snapshot = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok((path_buf.to_string_lossy().to_string(), Box::new(snapshot)))
+    }
+
+    async fn save_csv(process_list: Vec<ProcessExportData>) -> Result<String, String> {
+        let path_buf = data_dir()
+            .map(|dir| dir.join("processes_export.csv"))
+            .unwrap_or_else(|| PathBuf::from("processes_export.csv")); // Fallback
+
+        let path_str = path_buf.to_string_lossy().to_string();
+
+        if let Some(parent) = path_buf.parent() {
+            if !parent.exists() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        tokio::task::spawn_blocking(move || -> Result<(), String> {
+            let mut wtr = csv::Writer::from_path(path_buf)
+                .map_err(|e| e.to_string())?;
+            
+            for process in process_list {
+                wtr.serialize(process)
+                    .map_err(|e| e.to_string())?;
+            }
+            
+            wtr.flush().map_err(|e| e.to_string())?;
+            Ok(())
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+        Ok(path_str)
+    }
+
+    /// Tooltip body for a hovered process row, built from the live process so it is never stale.
+    fn tooltip_text(&self, pid: Pid) -> Option<String> {
+        if !self.tooltip_visible || self.hovered_process != Some(pid) {
+            return None;
+        }
+        let (name, exe, cmd) = match self.replayed_row(pid) {
+            Some(row) => (row.name.clone(), row.exe.clone(), row.cmd.clone()),
+            None => {
+                let process = self.system.process(pid)?;
+                let exe = process.exe().and_then(|p| p.to_str()).map(str::to_string);
+                (process.name().to_string(), exe, process.cmd().join(" "))
+            }
+        };
+        let mut lines = Vec::new();
+        if self.settings.tooltip_show_name {
+            lines.push(format!("{} (PID: {})", name, pid));
+        }
+        if self.settings.tooltip_show_exe {
+            lines.push(format!("Executable: {}", exe.as_deref().unwrap_or("N/A")));
+        }
+        if self.settings.tooltip_show_cmd {
+            lines.push(format!("Command: {}", cmd));
+        }
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    /// Name plus PID (and parent name when known), so rows sharing a name can be told apart
+    /// wherever a process is referred to outside the table.
+    fn process_label(&self, pid: Pid) -> String {
+        let Some(process) = self.live_process(pid) else {
+            return match self.process_list.iter().find(|p| p.pid == pid) {
+                Some(row) => format!("{} (PID {})", row.name, pid),
+                None => format!("process (PID {})", pid),
+            };
+        };
+        match process.parent().and_then(|ppid| self.system.process(ppid)) {
+            Some(parent) => format!("{} (PID {}, child of {})", process.name(), pid, parent.name()),
+            None => format!("{} (PID {})", process.name(), pid),
+        }
+    }
+
+    /// Plain-text summary of a process for the clipboard.
+    fn process_details(&self, pid: Pid) -> Option<String> {
+        let (name, cpu_usage, memory, status, exe, cmd) = match self.replayed_row(pid) {
+            Some(row) => (row.name.clone(), row.cpu_usage, row.memory, row.status, row.exe.clone(), row.cmd.clone()),
+            None => {
+                let process = self.system.process(pid)?;
+                (
+                    process.name().to_string(),
+                    cap_cpu(process.cpu_usage(), self.cpu_cap()),
+                    process.memory(),
+                    process.status(),
+                    process.exe().and_then(|p| p.to_str()).map(str::to_string),
+                    process.cmd().join(" "),
+                )
+            }
+        };
+        Some(format!(
+            "PID: {}\nName: {}\nCPU: {:.1}%\nMemory: {}\nStatus: {}\nExecutable: {}\nCommand: {}",
+            pid,
+            name,
+            cpu_usage,
+            self.format_memory(memory),
+            status,
+            exe.as_deref().unwrap_or("N/A"),
+            cmd,
+        ))
+    }
+
+    fn screenshot_path(&self) -> PathBuf {
+        let file_name = format!("system_monitor_{}.png", chrono::Local::now().format(AUTO_EXPORT_TIMESTAMP_FORMAT));
+        let dir = if self.settings.screenshot_dir.trim().is_empty() {
+            directories::UserDirs::new()
+                .and_then(|dirs| dirs.picture_dir().map(|p| p.to_path_buf()))
+                .or_else(data_dir)
+                .unwrap_or_default()
+        } else {
+            PathBuf::from(self.settings.screenshot_dir.trim())
+        };
+        dir.join(file_name)
+    }
+
+    async fn save_screenshot(screenshot: window::Screenshot, path_buf: PathBuf) -> Result<String, String> {
+        let path_str = path_buf.to_string_lossy().to_string();
+
+        if let Some(parent) = path_buf.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        // The screenshot API hands back raw RGBA pixels, so encode them as PNG ourselves.
+        tokio::task::spawn_blocking(move || -> Result<(), String> {
+            let image = image::RgbaImage::from_raw(
+                screenshot.size.width,
+                screenshot.size.height,
+                screenshot.bytes.to_vec(),
+            )
+            .ok_or_else(|| "Screenshot buffer does not match its size".to_string())?;
+            image.save_with_format(&path_buf, image::ImageFormat::Png)
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+        Ok(path_str)
+    }
+
+    /// Arrow for a metric's short-term trend. Rising load is tinted warm, falling load cool.
+    fn trend_arrow(&self, history: &VecDeque<f32>) -> Element<'static, Message, Theme, Renderer> {
+        let dark = self.effective_theme() == ThemeChoice::Dark;
+        let (arrow, color) = match trend(history, TREND_WINDOW, TREND_THRESHOLD) {
+            Trend::Rising if dark => ("▲", Color::from_rgb(1.0, 0.5, 0.4)),
+            Trend::Rising => ("▲", Color::from_rgb(0.75, 0.2, 0.1)),
+            Trend::Falling if dark => ("▼", Color::from_rgb(0.4, 0.8, 1.0)),
+            Trend::Falling => ("▼", Color::from_rgb(0.1, 0.4, 0.7)),
+            Trend::Flat => ("▬", Color::from_rgb(0.55, 0.55, 0.55)),
+        };
+        text(arrow).size(20).style(color).into()
+    }
+
+    /// Current usage of every core as a compact bar, so one pegged core can't hide
+    /// behind a calm global average.
+    fn view_core_usage_bars(&self) -> Element<'_, Message, Theme, Renderer> {
+        let columns = core_bar_columns(self.dashboard_data.per_core_usage.len());
+        let frequencies = &self.dashboard_data.per_core_frequency;
+        // Platforms that can't read frequencies report 0 for every core; leave the column out.
+        let show_frequency = frequencies.iter().any(|&mhz| mhz > 0);
+        self.dashboard_data.per_core_usage.chunks(columns).enumerate().fold(
+            column![].spacing(8),
+            |grid, (chunk_index, chunk)| {
+                let cells = chunk.iter().enumerate().fold(row![].spacing(15), |cells, (i, usage)| {
+                    let core = chunk_index * columns + i;
+                    let label = match frequencies.get(core) {
+                        Some(mhz) if show_frequency => format!("Core {}  {:.0}%  {} MHz", core, usage, mhz),
+                        _ => format!("Core {}  {:.0}%", core, usage),
+                    };
+                    cells.push(
+                        column![
+                            text(label).size(12),
+                            progress_bar(0.0..=100.0, *usage)
+                                .width(Length::Fixed(100.0))
+                                .height(Length::Fixed(8.0)),
+                        ]
+                        .spacing(2),
+                    )
+                });
+                grid.push(cells)
+            },
+        )
+        .into()
+    }
+
+    /// Every core as a small square coloured from green (idle) to red (busy), dense enough
+    /// to take in a many-core server at a glance.
+    fn view_core_heat_grid(&self) -> Element<'_, Message, Theme, Renderer> {
+        let columns = heat_grid_columns(self.dashboard_data.per_core_usage.len());
+        self.dashboard_data.per_core_usage.chunks(columns).fold(column![].spacing(4), |grid, chunk| {
+            let cells = chunk.iter().fold(row![].spacing(4), |cells, &usage| {
+                let background = heat_color(usage);
+                cells.push(
+                    Container::<Message, Theme, Renderer>::new(text(format!("{:.0}%", usage)).size(11).style(Color::WHITE))
+                        .width(Length::Fixed(40.0))
+                        .height(Length::Fixed(40.0))
+                        .center_x()
+                        .center_y()
+                        .style(move |_theme: &Theme| container::Appearance {
+                            background: Some(iced::Background::Color(background)),
+                            border: Border { radius: 4.0.into(), ..Default::default() },
+                            ..Default::default()
+                        }),
+                )
+            });
+            grid.push(cells)
+        })
+        .into()
+    }
+
+    /// CPU and memory usage over the history window, both on a 0–100% scale.
+    fn view_usage_history(&self) -> Element<'_, Message, Theme, Renderer> {
+        let span = format_duration(self.settings.refresh_interval_secs * HISTORY_LEN as u64);
+        let chart = |title: &str, history: &VecDeque<f32>, color: Color| {
+            column![
+                row![
+                    text(title).size(16),
+                    Space::with_width(Length::Fill),
+                    text(format!("last {} · 0–100%", span)).size(12),
+                ],
+                Container::<Message, Theme, Renderer>::new(
+                    Canvas::new(LineChart::new(history, HISTORY_LEN, 100.0, color))
+                        .width(Length::Fill)
+                        .height(Length::Fixed(120.0)),
+                )
+                .padding(5)
+                .style(iced::theme::Container::Box),
+            ]
+            .spacing(5)
+            .width(Length::Fixed(400.0))
+        };
+        row![
+            chart("CPU", &self.cpu_history, Color::from_rgb(0.3, 0.6, 0.9)),
+            chart("Memory", &self.mem_history, Color::from_rgb(0.6, 0.4, 0.9)),
+        ]
+        .spacing(20)
+        .into()
+    }
+
+    /// A card's sparkline, or just its reserved height when graphs are turned off.
+    fn card_sparkline<'a>(&self, history: &VecDeque<f32>, color: Color) -> Element<'a, Message, Theme, Renderer> {
+        if !self.settings.show_graphs {
+            return Space::with_height(self.sizing().sparkline_height).into();
+        }
+        sparkline(history, self.sizing(), color)
+    }
+
+    /// Grid of per-core sparklines so a single pegged core stands out over time.
+    fn view_core_history_grid(&self) -> Element<'_, Message, Theme, Renderer> {
+        const COLUMNS: usize = 8;
+        let grid = self.per_core_history.chunks(COLUMNS).enumerate().fold(
+            column![].spacing(10),
+            |grid, (chunk_index, chunk)| {
+                let cells = chunk.iter().enumerate().fold(row![].spacing(10), |cells, (i, history)| {
+                    let core = chunk_index * COLUMNS + i;
+                    let latest = history.back().copied().unwrap_or(0.0);
+                    cells.push(
+                        column![
+                            text(format!("Core {}  {:.0}%", core, latest)).size(12),
+                            Canvas::new(LineChart::new(history, HISTORY_LEN, 100.0, Color::from_rgb(0.3, 0.6, 0.9)))
+                                .width(Length::Fixed(130.0))
+                                .height(Length::Fixed(40.0)),
+                        ]
+                        .spacing(2),
+                    )
+                });
+                grid.push(cells)
+            },
+        );
+
+        Scrollable::new(grid)
+            .height(Length::Fixed(180.0))
+            .into()
+    }
+
+    /// Temperature readings, with components at or above their critical threshold in red.
+    fn view_sensors(&self) -> Element<'_, Message, Theme, Renderer> {
+        let content: Element<'_, Message, Theme, Renderer> = if self.sensor_data.is_empty() {
+            text("No sensor data available").into()
+        } else {
+            self.sensor_data.iter().fold(column![].spacing(5), |col, sensor| {
+                let reading = match sensor.critical {
+                    Some(critical) if critical > 0.0 => format!("{:.1} °C (critical {:.0} °C)", sensor.temperature, critical),
+                    _ => format!("{:.1} °C", sensor.temperature),
+                };
+                let reading = if sensor.is_critical() {
+                    text(reading).style(Color::from_rgb(0.9, 0.2, 0.2))
+                } else {
+                    text(reading)
+                };
+                col.push(row![text(&sensor.label).width(Length::Fill), reading].spacing(10))
+            })
+            .into()
+        };
+        Container::<Message, Theme, Renderer>::new(content)
+            .width(Length::Fixed(820.0))
+            .padding(10)
+            .style(iced::theme::Container::Box)
+            .into()
+    }
+
+    fn view_network(&self) -> Element<'_, Message, Theme, Renderer> {
+        let header = row![
+            text("Interface").width(Length::Fill),
+            text("RX/s").width(Length::Fixed(120.0)),
+            text("TX/s").width(Length::Fixed(120.0)),
+            text("Total RX").width(Length::Fixed(120.0)),
+            text("Total TX").width(Length::Fixed(120.0)),
+        ]
+        .spacing(10)
+        .padding(5);
+
+        let rows = self.network_data.iter().enumerate().fold(
+            column![
+                header,
+                Container::<Message, Theme, Renderer>::new(Space::with_height(2.0))
+                    .style(iced::theme::Container::Box)
+                    .width(Length::Fill),
+            ]
+            .spacing(5),
+            |rows, (index, network)| {
+                let row = row![
+                    text(&network.name).width(Length::Fill),
+                    text(format!("{}/s", self.format_memory(network.rx_per_sec as u64))).width(Length::Fixed(120.0)),
+                    text(format!("{}/s", self.format_memory(network.tx_per_sec as u64))).width(Length::Fixed(120.0)),
+                    text(self.format_memory(network.total_rx)).width(Length::Fixed(120.0)),
+                    text(self.format_memory(network.total_tx)).width(Length::Fixed(120.0)),
+                ]
+                .spacing(10)
+                .padding(5);
+                rows.push(self.striped_row(index, row.into()))
+            },
+        );
+
+        let table: Element<'_, Message, Theme, Renderer> = if self.network_data.is_empty() {
+            text("No network interfaces found").into()
+        } else {
+            Scrollable::new(rows).height(Length::Fixed(600.0)).into()
+        };
+
+        column![
+            text("Network Interfaces").size(24),
+            Space::with_height(10),
+            Container::<Message, Theme, Renderer>::new(table).width(Length::Fixed(900.0)),
+        ]
+        .align_items(Alignment::Center)
+        .into()
+    }
+
+    fn view_disks(&self) -> Element<'_, Message, Theme, Renderer> {
+        let disks: Element<'_, Message, Theme, Renderer> = if self.disk_data.is_empty() {
+            text("No disks found").into()
+        } else {
+            let list = self.disk_data.iter().fold(column![].spacing(15), |list, disk| {
+                let used = disk.used_percent();
+                let title = if disk.removable {
+                    format!("{} ({}, removable)", disk.mount_point, disk.file_system)
+                } else {
+                    format!("{} ({})", disk.mount_point, disk.file_system)
+                };
+                let bar_style = if used > DISK_USAGE_WARNING_PERCENT {
+                    iced::theme::ProgressBar::Danger
+                } else {
+                    iced::theme::ProgressBar::Primary
+                };
+                list.push(
+                    column![
+                        row![
+                            text(title).width(Length::Fill),
+                            text(&disk.name).size(14).style(Color::from_rgb(0.5, 0.5, 0.5)),
+                        ]
+                        .spacing(10),
+                        progress_bar(0.0..=100.0, used).height(Length::Fixed(12.0)).style(bar_style),
+                        text(format!(
+                            "{:.0}% used — {} free of {}",
+                            used,
+                            self.format_memory(disk.available),
+                            self.format_memory(disk.total),
+                        ))
+                        .size(14),
+                    ]
+                    .spacing(5),
+                )
+            });
+            Scrollable::new(list).height(Length::Fixed(600.0)).into()
+        };
+
+        column![
+            text("Disks").size(24),
+            Space::with_height(10),
+            Container::<Message, Theme, Renderer>::new(disks).width(Length::Fixed(700.0)),
+        ]
+        .align_items(Alignment::Center)
+        .into()
+    }
+
+    fn view_dashboard(&self) -> Element<'_, Message, Theme, Renderer> {
+        let sizing = self.sizing();
+        let header = row![
+            text(self.tr(UiText::AppTitle)).size(32),
+            Space::with_width(Length::Fill),
+            if self.is_paused {
+                text(self.tr(UiText::Paused)).style(Color::from_rgb(0.4, 0.6, 0.9))
+            } else {
+                text(self.tr(UiText::RealTime)).style(Color::from_rgb(0.3, 0.9, 0.3))
+            },
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        let cpu_value = format!("{:.1}%", self.displayed_cpu_usage);
+        let mut memory_value = format!(
+            "{} / {}",
+            self.format_memory(self.displayed_memory_used as u64),
+            self.format_memory(self.dashboard_data.memory_total),
+        );
+        if let Some((other_used, other_metric)) = self.dashboard_data.memory_other {
+            memory_value += &format!("\n{}: {}", self.tr(other_metric.label()), self.format_memory(other_used));
+        }
+        // "0 / 0 GiB" reads like a full swap device, so say plainly when there is none.
+        let swap_value = if self.dashboard_data.swap_total > 0 {
+            format!(
+                "{} / {}",
+                self.format_memory(self.dashboard_data.swap_used),
+                self.format_memory(self.dashboard_data.swap_total),
+            )
+        } else {
+            "No swap".to_string()
+        };
+        let process_value = format!("{} running", self.format_number(self.smoothed_process_count.round(), 0));
+
+        let data_cards = row![
+            create_chart_card(
+                sizing,
+                self.tr(UiText::CpuUsage),
+                cpu_value,
+                self.trend_arrow(&self.cpu_history),
+                self.card_sparkline(&self.cpu_history, Color::from_rgb(0.3, 0.6, 0.9)),
+            ),
+            create_chart_card(
+                sizing,
+                &format!("{} ({})", self.tr(UiText::Memory), self.tr(self.dashboard_data.memory_metric.label())),
+                memory_value,
+                self.trend_arrow(&self.mem_history),
+                self.card_sparkline(&self.mem_history, Color::from_rgb(0.6, 0.4, 0.9)),
+            ),
+            create_trend_card(sizing, self.tr(UiText::Swap), swap_value, self.trend_arrow(&self.swap_history)),
+            create_card(sizing, self.tr(UiText::TabProcesses), process_value),
+            self.view_uptime_card(),
+            create_card(sizing, self.tr(UiText::Cores), self.os_info.core_summary()),
+        ]
+        .push_maybe(self.dashboard_data.load_average.map(|[one, five, fifteen]| {
+            create_tinted_card(
+                sizing,
+                self.tr(UiText::Load),
+                format!("{:.2} / {:.2} / {:.2}", one, five, fifteen),
+                load_color(one, self.os_info.core_count),
+            )
+        }))
+        .push_maybe(self.frequency_summary().map(|summary| create_card(sizing, self.tr(UiText::CpuFrequency), summary)))
+        .spacing(20);
+
+        // No cards at all without the `gpu` feature or an NVIDIA GPU.
+        let gpu_cards = self.gpu_data.iter().enumerate().fold(row![].spacing(20), |cards, (index, gpu)| {
+            cards.push(create_card(
+                sizing,
+                &format!("GPU {} · {}", index, gpu.name),
+                format!(
+                    "{}% · {} / {}",
+                    gpu.utilization_percent,
+                    self.format_memory(gpu.memory_used),
+                    self.format_memory(gpu.memory_total),
+                ),
+            ))
+        });
+
+        let mut swappers: Vec<&ProcessData> = self.process_list.iter()
+            .filter(|p| p.swap.unwrap_or(0) > 0)
+            .collect();
+        swappers.sort_by_key(|p| std::cmp::Reverse(p.swap));
+        let top_swappers: Element<'_, Message, Theme, Renderer> = if swappers.is_empty() {
+            text(self.tr(UiText::NoSwapUsers)).into()
+        } else {
+            swappers.iter().take(5).fold(column![].spacing(5), |col, p| {
+                col.push(row![
+                    text(format!("{} ({})", p.name, p.pid)).width(Length::Fill),
+                    text(self.format_memory(p.swap.unwrap_or(0))),
+                ].spacing(10))
+            })
+            .into()
+        };
+
+        let top_cpu = self.cpu_by_user.first().map_or(0.0, |(_, cpu)| *cpu);
+        let cpu_by_user: Element<'_, Message, Theme, Renderer> = if self.cpu_by_user.is_empty() {
+            text(self.tr(UiText::NoProcesses)).into()
+        } else {
+            self.cpu_by_user.iter().fold(column![].spacing(5), |col, (user, cpu)| {
+                let share = if top_cpu > 0.0 { cpu / top_cpu } else { 0.0 };
+                col.push(row![
+                    text(user).width(Length::Fixed(120.0)),
+                    progress_bar(0.0..=1.0, share).height(Length::Fixed(8.0)),
+                    text(format!("{:.1}%", cpu)).width(Length::Fixed(60.0))
+                        .horizontal_alignment(alignment::Horizontal::Right),
+                ].spacing(10).align_items(Alignment::Center))
+            })
+            .into()
+        };
+
+        let top_memory: Element<'_, Message, Theme, Renderer> = if self.top_memory.is_empty() {
+            text(self.tr(UiText::NoProcesses)).into()
+        } else {
+            self.top_memory.iter().fold(column![].spacing(5), |col, (pid, name, memory)| {
+                col.push(
+                    Button::new(row![
+                        text(format!("{} ({})", name, pid)).width(Length::Fill),
+                        text(self.format_memory(*memory)),
+                    ].spacing(10))
+                    .on_press(Message::JumpToProcess(*pid))
+                    .style(iced::theme::Button::Text)
+                    .padding(0)
+                    .width(Length::Fill),
+                )
+            })
+            .into()
+        };
+
+        let breakdowns = row![
+            column![
+                text(self.tr(UiText::TopMemory)).size(20),
+                Container::<Message, Theme, Renderer>::new(top_memory)
+                    .width(Length::Fixed(320.0))
+                    .padding(10)
+                    .style(iced::theme::Container::Box),
+            ]
+            .spacing(5),
+            column![
+                text(self.tr(UiText::TopSwappers)).size(20),
+                Container::<Message, Theme, Renderer>::new(top_swappers)
+                    .width(Length::Fixed(320.0))
+                    .padding(10)
+                    .style(iced::theme::Container::Box),
+            ]
+            .spacing(5),
+            column![
+                text(self.tr(UiText::CpuByUser)).size(20),
+                Container::<Message, Theme, Renderer>::new(cpu_by_user)
+                    .width(Length::Fixed(320.0))
+                    .padding(10)
+                    .style(iced::theme::Container::Box),
+            ]
+            .spacing(5),
+        ]
+        .spacing(20);
+
+        let history_graphs = self.settings.show_graphs.then(|| {
+            column![
+                Space::with_height(sizing.section_gap),
+                text(self.tr(UiText::UsageHistory)).size(20),
+                self.view_usage_history(),
+            ]
+            .align_items(Alignment::Center)
+        });
+        let core_history = self.settings.show_graphs.then(|| {
+            column![
+                Space::with_height(sizing.section_gap),
+                text(self.tr(UiText::PerCoreHistory)).size(20),
+                self.view_core_history_grid(),
+            ]
+            .align_items(Alignment::Center)
+        });
+
+        let content = column![
+            header,
+            Space::with_height(sizing.section_gap),
+            text(self.tr(UiText::SystemOverview)).size(24),
+            Space::with_height(10),
+            data_cards,
+            Space::with_height(10),
+            self.view_status_chips(),
+            Space::with_height(if self.gpu_data.is_empty() { 0.0 } else { sizing.section_gap }),
+            gpu_cards,
+        ]
+        .push_maybe(history_graphs)
+        .push(column![
+            Space::with_height(sizing.section_gap),
+            text(self.tr(UiText::PerCoreUsage)).size(20),
+            self.view_core_usage_bars(),
+            Space::with_height(sizing.section_gap),
+            text(self.tr(UiText::CoreHeatMap)).size(20),
+            self.view_core_heat_grid(),
+        ].align_items(Alignment::Center))
+        .push_maybe(core_history)
+        .push(column![
+            Space::with_height(sizing.section_gap),
+            breakdowns,
+            Space::with_height(sizing.section_gap),
+            text(self.tr(UiText::Sensors)).size(20),
+            self.view_sensors(),
+        ].align_items(Alignment::Center))
+        .align_items(Alignment::Center);
+
+        Scrollable::new(content).height(Length::Fill).into()
+    }
+
+    /// One chip per process status, most common first, with zombies highlighted in red.
+    fn view_status_chips(&self) -> Element<'_, Message, Theme, Renderer> {
+        let zombie = ProcessStatus::Zombie.to_string();
+        let mut counts: Vec<(&String, &usize)> = self.status_counts.iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        counts.into_iter().fold(row![].spacing(10), |chips, (status, count)| {
+            let color = if *status == zombie && *count > 0 {
+                Color::from_rgb(0.8, 0.2, 0.2)
+            } else {
+                Color::from_rgb(0.4, 0.4, 0.45)
+            };
+            chips.push(create_badge(&format!("{} {}", status, count), color))
+        })
+        .into()
+    }
+
+    /// Average current core frequency, with the maximum when known, or `None` when the
+    /// platform reports no frequencies at all.
+    fn frequency_summary(&self) -> Option<String> {
+        let frequencies = &self.dashboard_data.per_core_frequency;
+        if !frequencies.iter().any(|&mhz| mhz > 0) {
+            return None;
+        }
+        let average = frequencies.iter().sum::<u64>() / frequencies.len() as u64;
+        Some(match self.dashboard_data.max_frequency {
+            Some(max) => format!("{} / {} MHz", average, max),
+            None => format!("{} MHz", average),
+        })
+    }
+
+    /// Contents of the About dialog: app version and what the monitor is running on.
+    fn view_about(&self) -> Element<'_, Message, Theme, Renderer> {
+        let unknown = |value: &Option<String>| value.clone().unwrap_or_else(|| "Unknown".to_string());
+        let os = match (&self.os_info.name, &self.os_info.os_version) {
+            (Some(name), Some(version)) => format!("{} {}", name, version),
+            (name, _) => unknown(name),
+        };
+        column![
+            text("System Monitor").size(24),
+            text(format!("Version {}", APP_VERSION)),
+            Space::with_height(10),
+            selectable_field("OS:", os),
+            selectable_field("Kernel:", unknown(&self.os_info.kernel_version)),
+            selectable_field("Host name:", unknown(&self.os_info.host_name)),
+            selectable_field(
+                "CPU:",
+                format!("{} ({} logical cores)", unknown(&self.os_info.cpu_brand), self.os_info.core_count),
+            ),
+            Space::with_height(20),
+            Button::new(text("Close"))
+                .on_press(Message::HideAbout)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .into()
+    }
+
+    /// Uptime card, with the boot time and (when the platform reports them) OS and kernel.
+    fn view_uptime_card(&self) -> Element<'_, Message, Theme, Renderer> {
+        let booted = chrono::DateTime::from_timestamp(self.dashboard_data.boot_time as i64, 0)
+            .map(|utc| utc.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string());
+        let os = match (&self.os_info.name, &self.os_info.kernel_version) {
+            (Some(name), Some(kernel)) => Some(format!("{} · kernel {}", name, kernel)),
+            (Some(name), None) => Some(name.clone()),
+            (None, Some(kernel)) => Some(format!("Kernel {}", kernel)),
+            (None, None) => None,
+        };
+        let details = [booted.map(|at| format!("Booted {}", at)), os]
+            .into_iter()
+            .flatten()
+            .fold(column![].spacing(2).align_items(Alignment::Center), |col, line| col.push(text(line).size(14)));
+        create_detail_card(self.sizing(), self.tr(UiText::Uptime), format_duration(self.dashboard_data.uptime_secs), details.into())
+    }
+
+    fn passes_filters(&self, row: &ProcessData) -> bool {
+        process_matches_filter(row, &self.process_filter, self.filter_command_line)
+            && meets_usage_minimums(row, parse_minimum(&self.min_cpu_input) as f32, parse_minimum(&self.min_mem_input))
+            && self.user_filter.as_ref().is_none_or(|user| row.user.as_ref() == Some(user))
+    }
+
+    /// The User column only earns its space when the table mixes several owners.
+    fn shows_user_column(&self) -> bool {
+        self.process_users.len() > 1
+    }
+
+    /// Drops the selection when the table filters no longer show it.
+    fn deselect_if_filtered_out(&mut self) {
+        let hidden = |pid| detail_row(&self.process_list, pid).is_some_and(|p| !self.passes_filters(p));
+        if self.selected_process.is_some_and(hidden) {
+            self.selected_process = None;
+            self.selected_process_name = None;
+            self.selection_baseline = None;
+        }
+    }
+
+    /// Processes matching the current view, in display order, before the row limit is applied.
+    fn visible_processes(&self) -> Vec<&ProcessData> {
+        let mut rows: Vec<&ProcessData> = self.process_list.iter()
+            .filter(|p| self.passes_filters(p))
+            .collect();
+        sort_rows(&mut rows, self.sort_column, self.sort_ascending);
+        rows
+    }
+
+    /// PIDs in the order the table renders them, before the row limit; `None` marks a group
+    /// header. Mirrors the layout in `view_process_content`.
+    fn table_order(&self) -> Vec<Option<Pid>> {
+        let visible = self.visible_processes();
+        match self.settings.grouping_mode {
+            GroupingMode::None => visible.iter().map(|p| Some(p.pid)).collect(),
+            GroupingMode::Tree => {
+                flatten_process_tree(&visible, self.settings.tree_depth_limit, &self.expanded_tree_nodes)
+                    .iter()
+                    .map(|row| Some(row.process.pid))
+                    .collect()
+            }
+            mode => group_processes(&visible, mode)
+                .iter()
+                .flat_map(|group| {
+                    let members = if self.expanded_groups.contains(&group.key) { &group.members[..] } else { &[] };
+                    std::iter::once(None).chain(members.iter().map(|p| Some(p.pid)))
+                })
+                .collect(),
+        }
+    }
+
+    /// Expands the group or tree branches that fold `pid` away, so its row is rendered.
+    fn reveal_in_table(&mut self, pid: Pid) {
+        match self.settings.grouping_mode {
+            GroupingMode::None => {}
+            GroupingMode::Tree => {
+                let mut seen = std::collections::HashSet::from([pid]);
+                let mut ancestor = detail_row(&self.process_list, pid).and_then(|p| p.parent);
+                // `seen` stops the walk at a PID cycle.
+                while let Some(ppid) = ancestor.filter(|ppid| seen.insert(*ppid)) {
+                    self.expanded_tree_nodes.insert(ppid);
+                    ancestor = detail_row(&self.process_list, ppid).and_then(|p| p.parent);
+                }
+            }
+            mode => {
+                let visible = self.visible_processes();
+                let key = group_processes(&visible, mode)
+                    .into_iter()
+                    .find(|group| group.members.iter().any(|p| p.pid == pid))
+                    .map(|group| group.key);
+                self.expanded_groups.extend(key);
+            }
+        }
+    }
+
+    /// The table and the detail pane for `width` pixels: side by side when there is room,
+    /// otherwise stacked with proportional column widths.
+    fn view_process_content<'a>(&'a self, visible: &[&'a ProcessData], width: f32) -> Element<'a, Message, Theme, Renderer> {
+        let narrow = width < NARROW_LAYOUT_WIDTH;
+        let total_visible = visible.len();
+        let limit = self.settings.process_row_limit;
+
+        let mut process_rows = column![
+            self.view_process_header(narrow),
+            Container::<Message, Theme, Renderer>::new(Space::with_height(2.0))
+                .style(iced::theme::Container::Box)
+                .width(Length::Fill)
+        ].spacing(5);
+
+        let max_memory = visible.iter().map(|p| p.memory).max().unwrap_or(0);
+
+        // `shown` counts rendered rows (group headers included) against the row limit.
+        let mut shown = 0;
+        let mut truncated = false;
+        match self.settings.grouping_mode {
+            GroupingMode::None => {
+                for (index, process) in visible.iter().take(limit).enumerate() {
+                    process_rows = process_rows.push(self.striped_row(index, self.view_process_row(process, max_memory, None, narrow)));
+                }
+                shown = total_visible.min(limit);
+                truncated = total_visible > limit;
+            }
+            GroupingMode::Tree => {
+                let tree = flatten_process_tree(visible, self.settings.tree_depth_limit, &self.expanded_tree_nodes);
+                for (index, tree_row) in tree.iter().take(limit).enumerate() {
+                    let row = self.view_process_row(tree_row.process, max_memory, Some(tree_row), narrow);
+                    process_rows = process_rows.push(self.striped_row(index, row));
+                }
+                shown = tree.len().min(limit);
+                truncated = tree.len() > limit;
+            }
+            mode => {
+                'groups: for group in group_processes(visible, mode) {
+                    let expanded = self.expanded_groups.contains(&group.key);
+                    let members: &[&ProcessData] = if expanded { &group.members } else { &[] };
+                    if shown >= limit {
+                        truncated = true;
+                        break;
+                    }
+                    process_rows = process_rows.push(self.view_group_header(&group, expanded, narrow));
+                    shown += 1;
+                    for process in members {
+                        if shown >= limit {
+                            truncated = true;
+                            break 'groups;
+                        }
+                        process_rows = process_rows.push(self.striped_row(shown, self.view_process_row(process, max_memory, None, narrow)));
+                        shown += 1;
+                    }
+                }
+            }
+        }
+
+        let process_rows: Element<'_, Message, Theme, Renderer> = if truncated {
+            process_rows
+                .push(
+                    text(format!("showing {} of {} — refine filter to see more", shown, total_visible))
+                        .size(14),
+                )
+                .into()
+        } else {
+            process_rows.into()
+        };
+
+        let process_table = Scrollable::new(process_rows)
+            .id(process_table_id())
+            .width(if narrow { Length::Fill } else { Length::FillPortion(2) })
+            .height(if narrow { Length::FillPortion(3) } else { Length::Fill });
+
+        let comparison = self.compare_process.zip(self.selected_process).filter(|(a, b)| a != b);
+        let detail_pane: Element<'_, Message, Theme, Renderer> = if let Some((pinned, selected)) = comparison {
+            self.view_compare(pinned, selected)
+        } else if let Some(pid) = self.selected_process {
+            match detail_row(&self.process_list, pid) {
+                Some(row) => self.view_process_details(row),
+                None => Container::<Message, Theme, Renderer>::new(text(self.tr(UiText::ProcessDisappeared)))
+                    .width(Length::Fill)
+                    .align_x(alignment::Horizontal::Center)
+                    .center_y()
+                    .into(),
+            }
+        } else {
+            Container::<Message, Theme, Renderer>::new(text(self.tr(UiText::SelectProcess)))
+                .width(Length::Fill)
+                .align_x(alignment::Horizontal::Center)
+                .center_y()
+                .into()
+        };
+
+        let detail_container = Container::<Message, Theme, Renderer>::new(detail_pane)
+            .width(if narrow { Length::Fill } else { Length::FillPortion(1) })
+            .height(if narrow { Length::FillPortion(2) } else { Length::Fill })
+            .style(iced::theme::Container::Box);
+
+        if narrow {
+            column![process_table, detail_container].spacing(20).into()
+        } else {
+            Container::<Message, Theme, Renderer>::new(
+                row![process_table, detail_container].spacing(20).width(Length::Fixed(width.min(1200.0))),
+            )
+            .width(Length::Fill)
+            .center_x()
+            .into()
+        }
+    }
+
+    fn view_processes(&self) -> Element<'_, Message, Theme, Renderer> {
+        let visible = self.visible_processes();
+        let total_visible = visible.len();
+        let content_row = responsive(move |size| self.view_process_content(&visible, size.width));
+        let grouping = Some(self.settings.grouping_mode);
+        let grouping_row = row![
+            text(self.tr(UiText::GroupBy)),
+            Radio::new(self.tr(UiText::GroupNone), GroupingMode::None, grouping, Message::GroupingModeChanged),
+            Radio::new(self.tr(UiText::GroupName), GroupingMode::Name, grouping, Message::GroupingModeChanged),
+            Radio::new(self.tr(UiText::GroupExecutable), GroupingMode::ExePath, grouping, Message::GroupingModeChanged),
+            Radio::new(self.tr(UiText::GroupTree), GroupingMode::Tree, grouping, Message::GroupingModeChanged),
+        ]
+        .spacing(15)
+        .align_items(Alignment::Center);
+
+        let filtering = !self.process_filter.is_empty()
+            || parse_minimum(&self.min_cpu_input) > 0.0
+            || parse_minimum(&self.min_mem_input) > 0.0
+            || self.user_filter.is_some();
+        let all_users = UserChoice::All(self.tr(UiText::AllUsers));
+        let user_choices: Vec<UserChoice> = std::iter::once(all_users.clone())
+            .chain(self.process_users.iter().cloned().map(UserChoice::User))
+            .collect();
+        let filter_row = row![
+            // Takes the width left over, so the row still fits a narrow window.
+            text_input(self.tr(UiText::FilterByName), &self.process_filter)
+                .on_input(Message::ProcessFilterChanged)
+                .width(Length::Fill),
+            checkbox(self.tr(UiText::SearchCommandLine), self.filter_command_line)
+                .on_toggle(Message::FilterCommandLineToggled),
+            iced::widget::pick_list(
+                user_choices,
+                Some(self.user_filter.clone().map_or(all_users, UserChoice::User)),
+                |choice| Message::UserFilterChanged(match choice {
+                    UserChoice::All(_) => None,
+                    UserChoice::User(name) => Some(name),
+                }),
+            )
+            .width(Length::Fixed(140.0)),
+            text(self.tr(UiText::MinCpu)),
+            text_input(self.tr(UiText::Any), &self.min_cpu_input)
+                .on_input(Message::MinCpuFilterChanged)
+                .width(Length::Fixed(70.0)),
+            text(self.tr(UiText::MinMemory)),
+            text_input(self.tr(UiText::Any), &self.min_mem_input)
+                .on_input(Message::MinMemFilterChanged)
+                .width(Length::Fixed(90.0)),
+            if !filtering {
+                text("")
+            } else {
+                text(self.tr(UiText::ProcessesMatch).replacen("{}", &total_visible.to_string(), 1).replacen("{}", &self.process_list.len().to_string(), 1)).size(14)
+            },
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        column![
+            Container::<Message, Theme, Renderer>::new(filter_row).max_width(1200.0),
+            Space::with_height(10),
+            grouping_row,
+            Space::with_height(10),
+            content_row,
+            Space::with_height(10),
+            self.view_event_log(),
+            Space::with_height(15),
+            row![
+                Button::new(text(self.tr(UiText::RefreshProcesses)))
+                    .on_press(Message::RefreshProcesses)
+                    .style(iced::theme::Button::Secondary)
+                    .padding(10),
+                Button::new(text(self.tr(UiText::CopyVisiblePids)))
+                    .on_press(Message::CopyVisiblePids)
+                    .style(iced::theme::Button::Secondary)
+                    .padding(10),
+                Button::new(text(self.tr(UiText::ExportCsv)))
+                    .on_press(Message::ExportCsvRequested)
+                    .padding(10),
+                Button::new(text(self.tr(UiText::SaveSnapshot)))
+                    .on_press(Message::ExportSnapshot)
+                    .style(iced::theme::Button::Secondary)
+                    .padding(10),
+                text_input(self.tr(UiText::SnapshotFile), &self.snapshot_path_input)
+                    .on_input(Message::SnapshotPathChanged)
+                    .on_submit(Message::LoadSnapshot)
+                    .width(Length::Fixed(260.0))
+                    .padding(10),
+                Button::new(text(self.tr(UiText::LoadSnapshot)))
+                    .on_press(Message::LoadSnapshot)
+                    .style(iced::theme::Button::Secondary)
+                    .padding(10),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            if self.settings.auto_export_enabled {
+                let last = self.last_auto_export
+                    .map_or("not yet".to_string(), |t| t.format("%H:%M:%S").to_string());
+                text(format!(
+                    "Auto-export every {} min — last auto-export at {}",
+                    self.settings.auto_export_interval_mins, last
+                ))
+                .size(14)
+                .style(Color::from_rgb(0.5, 0.5, 0.5))
+            } else {
+                text("")
+            }
+        ]
+        .align_items(Alignment::Center)
+        .into()
+    }
+
+    /// Collapsible list of process starts and exits seen by the process refresh.
+    fn view_event_log(&self) -> Element<'_, Message, Theme, Renderer> {
+        let toggle = Button::new(text(format!(
+            "{} {} ({})",
+            if self.show_event_log { "▾" } else { "▸" },
+            self.tr(UiText::Events),
+            self.event_log.len(),
+        )))
+        .on_press(Message::EventLogToggled)
+        .style(iced::theme::Button::Text);
+        if !self.show_event_log {
+            return toggle.into();
+        }
+        let lines: Element<'_, Message, Theme, Renderer> = if self.event_log.is_empty() {
+            text(self.tr(UiText::NoEvents)).size(14).into()
+        } else {
+            self.event_log.iter()
+                .fold(column![].spacing(2), |col, line| col.push(text(line).size(14)))
+                .into()
+        };
+        column![
+            toggle,
+            Container::<Message, Theme, Renderer>::new(
+                Scrollable::new(lines).id(event_log_id()).width(Length::Fill),
+            )
+            .height(Length::Fixed(150.0))
+            .width(Length::Fill)
+            .padding(10)
+            .style(iced::theme::Container::Box),
+        ]
+        .spacing(5)
+        .into()
+    }
+
+    /// Detail pane for the selected row. Metrics come from the process list snapshot, which
+    /// survives re-sorting and keeps exited rows for the grace period, so the pane only falls
+    /// back to "disappeared" once the row is really gone. Live-only fields (command line,
+    /// environment) are read from sysinfo when the process is still there.
+    fn view_process_details<'a>(&'a self, row: &'a ProcessData) -> Element<'a, Message, Theme, Renderer> {
+        let pid = row.pid;
+        let exited = row.exited_at.is_some();
+        let live = self.system.process(pid).filter(|_| !exited && !self.is_replaying);
+        let title = if exited {
+            format!("Details for: {} (exited)", self.process_label(pid))
+        } else {
+            format!("Details for: {}", self.process_label(pid))
+        };
+        let actions: Element<'_, Message, Theme, Renderer> = if exited {
+            Space::with_height(0).into()
+        } else {
+            let buttons = self.detail_actions().into_iter().map(|action| self.view_detail_action(action, row));
+            let debugger = self.settings.developer_tools_enabled.then(|| {
+                Button::new(text("🐞 Open in debugger"))
+                    .on_press_maybe((!self.is_read_only()).then_some(Message::OpenInDebugger(pid)))
+                    .style(iced::theme::Button::Secondary)
+                    .padding(10)
+                    .into()
+            });
+            column(buttons.chain(debugger)).spacing(10).into()
+        };
+
+        column![
+            text(title).size(24),
+            Space::with_height(10),
+            selectable_field("PID:", pid.to_string()),
+            self.view_parent_link(row.parent),
+            text(format!("CPU: {:.1} %", row.cpu_usage)),
+            text(format!("Memory: {}", self.format_memory(row.memory))),
+            text(format!("Started: {}", start_time_label(row.start_time))),
+            text(format!("Running for: {}", run_time_label(row.run_time))),
+            row![
+                text(self.tr(UiText::StatusLabel)),
+                self.status_label(row.status),
+            ]
+            .spacing(5),
+            text(format!(
+                "Threads: {} · Open files: {}",
+                // sysinfo leaves the main thread, whose TID is the PID, out of `tasks`.
+                live.and_then(|p| p.tasks()).map_or("N/A".to_string(), |tasks| (tasks.len() + 1).to_string()),
+                self.selected_fd_count
+                    .filter(|(fd_pid, _)| *fd_pid == pid)
+                    .and_then(|(_, count)| count)
+                    .map_or("N/A".to_string(), |count| count.to_string()),
+            )),
+            selectable_field("Executable:", row.exe.clone().unwrap_or_else(|| "N/A".to_string())),
+            selectable_field("Command:", live.map_or("N/A".to_string(), |p| p.cmd().join(" "))),
+            selectable_field(
+                "Environment:",
+                match live {
+                    Some(process) if !process.environ().is_empty() => process.environ().join(" "),
+                    _ => "N/A".to_string(),
+                },
+            ),
+            self.view_selection_delta(pid, row.cpu_usage, row.memory),
+            match self.session_averages.get(&pid) {
+                Some(average) => text(format!(
+                    "Session average: CPU {:.1} % · Memory {} ({} samples)",
+                    average.cpu_usage,
+                    self.format_memory(average.memory as u64),
+                    self.format_number(average.samples as f64, 0),
+                )),
+                None => text(""),
+            },
+            Space::with_height(Length::Fill),
+            actions,
+        ]
+        .spacing(10)
+        .padding(20)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Enabled detail pane actions in order, without duplicates. Kill is always offered.
+    fn detail_actions(&self) -> Vec<DetailAction> {
+        let mut actions: Vec<DetailAction> = Vec::new();
+        for &action in &self.settings.detail_actions {
+            if !actions.contains(&action) {
+                actions.push(action);
+            }
+        }
+        if !actions.contains(&DetailAction::Kill) {
+            actions.push(DetailAction::Kill);
+        }
+        actions
+    }
+
+    fn view_detail_action(&self, action: DetailAction, row: &ProcessData) -> Element<'_, Message, Theme, Renderer> {
+        let pid = row.pid;
+        if action.is_destructive() && self.is_read_only() {
+            return Button::new(text(format!("🔒 {} disabled (read-only)", action.label())))
+                .style(iced::theme::Button::Secondary)
+                .padding(10)
+                .into();
+        }
+        if matches!(action, DetailAction::PopOutGraph | DetailAction::Compare) && self.is_replaying {
+            return Button::new(text(format!("⏪ {} unavailable in replay", action.label())))
+                .style(iced::theme::Button::Secondary)
+                .padding(10)
+                .into();
+        }
+        let (label, message) = match action {
+            DetailAction::Kill => {
+                return column![
+                    Button::new(text(self.tr(UiText::KillProcess)).style(Color::WHITE))
+                        .on_press(Message::KillProcessRequested(pid))
+                        .style(iced::theme::Button::Destructive)
+                        .padding(10),
+                    row![
+                        iced::widget::pick_list(&SIGNAL_CHOICES[..], Some(self.selected_signal), Message::SignalSelected)
+                            .width(Length::Fixed(140.0)),
+                        Button::new(text(format!("Send {}", signal_name(self.selected_signal))))
+                            .on_press(Message::SendSignal(pid, self.selected_signal))
+                            .style(iced::theme::Button::Secondary)
+                            .padding(10),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                ]
+                .spacing(10)
+                .into();
+            }
+            DetailAction::Suspend if row.status == ProcessStatus::Stop => ("Resume", Message::SuspendToggled(pid)),
+            DetailAction::Suspend => ("Suspend", Message::SuspendToggled(pid)),
+            DetailAction::Renice => {
+                let allowed = self.system.process(pid).is_some_and(can_adjust_priority);
+                let step = |label: &str, delta: i32| {
+                    Button::new(text(label.to_string()))
+                        .on_press_maybe(allowed.then_some(Message::AdjustPriority(pid, delta)))
+                        .style(iced::theme::Button::Secondary)
+                        .padding(10)
+                };
+                let current = match self.selected_nice.filter(|(nice_pid, _)| *nice_pid == pid).and_then(|(_, nice)| nice) {
+                    Some(nice) => format!("Nice {}", nice),
+                    None => "Nice unknown".to_string(),
+                };
+                return row![
+                    step(&format!("Nice +{}", NICE_STEP), NICE_STEP),
+                    step(&format!("Nice -{}", NICE_STEP), -NICE_STEP),
+                    text(if allowed { current } else { format!("{} (owned by another user)", current) }),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into();
+            }
+            DetailAction::Copy => ("Copy details", Message::CopyProcessDetails(pid)),
+            DetailAction::OpenLocation => ("Open file location", Message::OpenLocationRequested(pid)),
+            DetailAction::Relaunch => ("Relaunch", Message::LaunchAnotherInstance(pid)),
+            DetailAction::Restart => ("Restart", Message::RestartProcess(pid)),
+            DetailAction::PopOutGraph => ("Pop out graph", Message::PopOutGraph(pid)),
+            DetailAction::Compare => ("Pin for comparison", Message::CompareProcessPinned(pid)),
+        };
+        Button::new(text(label))
+            .on_press(message)
+            .style(iced::theme::Button::Secondary)
+            .padding(10)
+            .into()
+    }
+
+    /// Settings rows for choosing and ordering detail pane actions.
+    fn view_detail_action_settings(&self) -> Element<'_, Message, Theme, Renderer> {
+        let enabled = self.detail_actions();
+        let disabled = DetailAction::ALL.into_iter().filter(|a| !enabled.contains(a));
+        let rows = enabled.iter().copied().chain(disabled).map(|action| {
+            let is_enabled = enabled.contains(&action);
+            let position = enabled.iter().position(|a| *a == action);
+            row![
+                checkbox(action.label(), is_enabled)
+                    .on_toggle_maybe((action != DetailAction::Kill).then_some(move |on| {
+                        Message::DetailActionToggled(action, on)
+                    }))
+                    .width(Length::Fixed(220.0)),
+                Button::new(text("↑"))
+                    .on_press_maybe(position.filter(|p| *p > 0).map(|_| Message::DetailActionMoved(action, true)))
+                    .style(iced::theme::Button::Secondary),
+                Button::new(text("↓"))
+                    .on_press_maybe(
+                        position.filter(|p| p + 1 < enabled.len()).map(|_| Message::DetailActionMoved(action, false)),
+                    )
+                    .style(iced::theme::Button::Secondary),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .into()
+        });
+        column![
+            text("Detail Pane Actions").size(20),
+            text("Kill is always available; order applies to enabled actions.").size(14),
+        ]
+        .extend(rows)
+        .spacing(5)
+        .into()
+    }
+
+    /// Side-by-side metrics of the pinned and the selected process, larger values highlighted.
+    fn view_compare(&self, pinned: Pid, selected: Pid) -> Element<'_, Message, Theme, Renderer> {
+        let (Some(a), Some(b)) = (self.live_process(pinned), self.live_process(selected)) else {
+            return Container::<Message, Theme, Renderer>::new(text(self.tr(UiText::ProcessDisappeared)))
+                .width(Length::Fill)
+                .align_x(alignment::Horizontal::Center)
+                .center_y()
+                .into();
+        };
+        let highlight = Color::from_rgb(1.0, 0.65, 0.2);
+        let cell = |value: String, emphasize: bool| {
+            let label = text(value).width(Length::FillPortion(2));
+            if emphasize { label.style(highlight) } else { label }
+        };
+        let compare_row = |label: &str, a_value: String, b_value: String, a_more: bool, b_more: bool| {
+            row![
+                text(label).width(Length::FillPortion(1)),
+                cell(a_value, a_more),
+                cell(b_value, b_more),
+            ]
+            .spacing(10)
+        };
+        let differs = |x: String, y: String| {
+            let changed = x != y;
+            (x, y, changed, changed)
+        };
+
+        let cpu_cap = self.cpu_cap();
+        let (a_cpu, b_cpu) = (cap_cpu(a.cpu_usage(), cpu_cap), cap_cpu(b.cpu_usage(), cpu_cap));
+        let (a_mem, b_mem) = (a.memory(), b.memory());
+        let to_mb = |bytes: u64| self.format_memory(bytes);
+        let (a_status, b_status, a_status_diff, b_status_diff) =
+            differs(a.status().to_string(), b.status().to_string());
+        let (a_exe, b_exe, a_exe_diff, b_exe_diff) = differs(
+            a.exe().map_or("N/A".to_string(), |p| p.to_string_lossy().to_string()),
+            b.exe().map_or("N/A".to_string(), |p| p.to_string_lossy().to_string()),
+        );
+
+        column![
+            text("Compare Processes").size(24),
+            Space::with_height(10),
+            compare_row("", format!("📌 {}", a.name()), b.name().to_string(), false, false),
+            compare_row("PID", pinned.to_string(), selected.to_string(), false, false),
+            compare_row("CPU", format!("{:.1} %", a_cpu), format!("{:.1} %", b_cpu), a_cpu > b_cpu, b_cpu > a_cpu),
+            compare_row("Memory", to_mb(a_mem), to_mb(b_mem), a_mem > b_mem, b_mem > a_mem),
+            compare_row("Status", a_status, b_status, a_status_diff, b_status_diff),
+            compare_row("Executable", a_exe, b_exe, a_exe_diff, b_exe_diff),
+            compare_row(
+                "Run time",
+                format_duration(a.run_time()),
+                format_duration(b.run_time()),
+                a.run_time() > b.run_time(),
+                b.run_time() > a.run_time(),
+            ),
+            Space::with_height(Length::Fill),
+            Button::new(text("Clear comparison"))
+                .on_press(Message::CompareCleared)
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+        ]
+        .spacing(10)
+        .padding(20)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// "Parent:" line for the detail pane; the parent can be jumped to while it is running.
+    fn view_parent_link(&self, parent: Option<Pid>) -> Element<'_, Message, Theme, Renderer> {
+        let Some(ppid) = parent else {
+            return text("Parent: none").into();
+        };
+        let name = match self.replayed_row(ppid) {
+            Some(row) => Some(row.name.clone()),
+            None => self.live_process(ppid).map(|p| p.name().to_string()),
+        };
+        match name {
+            Some(name) => row![
+                text(format!("Parent: {} ({})", name, ppid)),
+                Button::new(text("Go to parent").size(12))
+                    .on_press(Message::JumpToProcess(ppid))
+                    .style(iced::theme::Button::Secondary)
+                    .padding([2, 6]),
+            ]
+            .spacing(8)
+            .align_items(Alignment::Center)
+            .into(),
+            None => text(format!("Parent: {} (exited)", ppid)).into(),
+        }
+    }
+
+    /// Live "since selected" timer and CPU/memory change relative to the selection baseline.
+    fn view_selection_delta(&self, pid: Pid, cpu_usage: f32, memory: u64) -> Element<'_, Message, Theme, Renderer> {
+        let Some(baseline) = self.selection_baseline.filter(|b| b.pid == pid) else {
+            return Space::with_height(0.0).into();
+        };
+        let cpu_delta = cpu_usage - baseline.cpu_usage;
+        let mem_delta = if memory >= baseline.memory {
+            format!("+{}", self.format_memory(memory - baseline.memory))
+        } else {
+            format!("-{}", self.format_memory(baseline.memory - memory))
+        };
+        column![
+            text(format!("Selected for: {}", format_duration(baseline.selected_at.elapsed().as_secs()))),
+            text(format!("CPU since selected: {:+.1} %", cpu_delta)),
+            text(format!("Memory since selected: {}", mem_delta)),
+        ]
+        .spacing(5)
+        .into()
+    }
+
+    fn view_process_header(&self, narrow: bool) -> Element<'_, Message, Theme, Renderer> {
+        let header = |label: &str, column: SortColumn, width: Length| {
+            let label = if self.sort_column == column {
+                format!("{} {}", label, if self.sort_ascending { "▲" } else { "▼" })
+            } else {
+                label.to_string()
+            };
+            Button::new(text(label))
+                .on_press(Message::SortBy(column))
+                .style(iced::theme::Button::Text)
+                .padding(0)
+                .width(width)
+        };
+        self.visible_columns()
+            .fold(row![], |row, column| row.push(header(column.label(), column, self.column_width(column, narrow))))
+            .spacing(10)
+            .padding(5)
+            .into()
+    }
+
+    /// Width of the Memory column, wider when the inline share bar is shown.
+    fn memory_column_width(&self, narrow: bool) -> Length {
+        match (narrow, self.settings.show_memory_bars) {
+            (false, bars) => Length::Fixed(if bars { 170.0 } else { 100.0 }),
+            (true, bars) => Length::FillPortion(if bars { 2 } else { 1 }),
+        }
+    }
+
+    /// Enabled table columns in table order.
+    fn visible_columns(&self) -> impl Iterator<Item = SortColumn> + '_ {
+        SortColumn::ALL.into_iter().filter(|column| {
+            self.settings.visible_columns.contains(column) && (*column != SortColumn::User || self.shows_user_column())
+        })
+    }
+
+    /// Name takes whatever width is left; with it hidden the remaining columns pack to the left.
+    /// In the narrow layout every column gets a share of the width instead of fixed pixels.
+    fn column_width(&self, column: SortColumn, narrow: bool) -> Length {
+        match column {
+            SortColumn::Name if narrow => Length::FillPortion(3),
+            SortColumn::Name => Length::Fill,
+            SortColumn::Memory => self.memory_column_width(narrow),
+            SortColumn::StartTime => extra_column_width(160.0, narrow),
+            SortColumn::Status => extra_column_width(120.0, narrow),
+            _ => extra_column_width(100.0, narrow),
+        }
+    }
+
+    /// `max_memory` is the largest process in the current view, which fills the share bar.
+    /// `tree_row` indents the name and adds the expand/collapse button in the tree view.
+    fn view_process_row<'a>(
+        &'a self,
+        process: &'a ProcessData,
+        max_memory: u64,
+        tree_row: Option<&TreeRow<'a>>,
+        narrow: bool,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        let pid = process.pid;
+        let process_row = self.visible_columns().fold(row![], |row, column| {
+            let width = self.column_width(column, narrow);
+            let cell: Element<'a, Message, Theme, Renderer> = match column {
+                SortColumn::Pid => text(pid.to_string()).width(width).into(),
+                SortColumn::Name => self.view_name_cell(process, tree_row, width),
+                SortColumn::Cpu => text(format!("{:.1}", process.cpu_usage)).width(width).into(),
+                SortColumn::Memory => self.view_memory_cell(process.memory, max_memory, width),
+                SortColumn::Swap => text(process.swap.map_or("-".to_string(), |swap| self.format_memory(swap)))
+                    .width(width)
+                    .into(),
+                SortColumn::DiskRead => text(format!("{}/s", self.format_memory(process.disk_read_rate as u64))).width(width).into(),
+                SortColumn::DiskWrite => text(format!("{}/s", self.format_memory(process.disk_write_rate as u64))).width(width).into(),
+                SortColumn::StartTime => text(start_time_label(process.start_time)).width(width).into(),
+                SortColumn::User => text(process.user.as_deref().unwrap_or("-")).width(width).into(),
+                SortColumn::Status => Container::<Message, Theme, Renderer>::new(self.status_label(process.status))
+                    .width(width)
+                    .into(),
+                SortColumn::RunTime => text(run_time_label(process.run_time)).width(width).into(),
+            };
+            row.push(cell)
+        })
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .padding(self.sizing().row_padding);
+
+        let row_button = Button::new(process_row)
+            .on_press(Message::ProcessSelected(pid))
+            .style(if self.selected_process == Some(pid) {
+                iced::theme::Button::Primary
+            } else {
+                iced::theme::Button::Text
+            });
+
+        let row_content: Element<'_, Message, Theme, Renderer> =
+            match self.tooltip_text(pid) {
+                Some(tip) => tooltip(row_button, text(tip), tooltip::Position::FollowCursor)
+                    .style(iced::theme::Container::Box)
+                    .padding(8)
+                    .into(),
+                None => row_button.into(),
+            };
+
+        let row_area = mouse_area(row_content)
+            .on_enter(Message::ProcessHovered(pid))
+            .on_exit(Message::ProcessHoverEnded(pid))
+            .on_right_press(Message::ProcessContextMenu(pid));
+        if self.context_menu != Some(pid) {
+            return row_area.into();
+        }
+        column![row_area, self.view_context_menu(process)].into()
+    }
+
+    /// `tree_row` indents the name and adds the expand/collapse button in the tree view.
+    fn view_name_cell<'a>(
+        &self,
+        process: &'a ProcessData,
+        tree_row: Option<&TreeRow<'a>>,
+        width: Length,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        let pid = process.pid;
+        let name = if process.exited_at.is_some() {
+            text(format!("{} (exited)", process.name)).style(Color::from_rgb(0.5, 0.5, 0.5))
+        } else {
+            text(process.name.clone())
+        };
+        let name_cell = match tree_row {
+            Some(tree_row) => {
+                let toggle = if tree_row.hidden_descendants > 0 {
+                    Some(format!("+{} more", tree_row.hidden_descendants))
+                } else if tree_row.has_children && self.expanded_tree_nodes.contains(&pid) {
+                    Some("collapse".to_string())
+                } else {
+                    None
+                };
+                row![Space::with_width(Length::Fixed(16.0 * tree_row.depth as f32)), name]
+                    .push_maybe(toggle.map(|label| {
+                        Button::new(text(label).size(12))
+                            .on_press(Message::TreeNodeToggled(pid))
+                            .style(iced::theme::Button::Secondary)
+                            .padding([2, 6])
+                    }))
+                    .spacing(8)
+                    .align_items(Alignment::Center)
+                    .width(width)
+            }
+            None => row![name].width(width),
+        };
+        name_cell.into()
+    }
+
+    /// Right-click menu for a process row, reusing the kill and copy actions.
+    fn view_context_menu<'a>(&self, process: &'a ProcessData) -> Element<'a, Message, Theme, Renderer> {
+        let kill: Element<'a, Message, Theme, Renderer> = if self.is_read_only() {
+            Button::new(text("🔒 Kill").size(14)).style(iced::theme::Button::Secondary).into()
+        } else {
+            Button::new(text("Kill").size(14))
+                .on_press(Message::KillProcessRequested(process.pid))
+                .style(iced::theme::Button::Destructive)
+                .into()
+        };
+        Container::<Message, Theme, Renderer>::new(
+            row![
+                kill,
+                Button::new(text("Copy PID").size(14))
+                    .on_press(Message::CopyToClipboard(process.pid.to_string()))
+                    .style(iced::theme::Button::Secondary),
+                Button::new(text("Copy Name").size(14))
+                    .on_press(Message::CopyToClipboard(process.name.clone()))
+                    .style(iced::theme::Button::Secondary),
+            ]
+            .spacing(5),
+        )
+        .padding(5)
+        .style(iced::theme::Container::Box)
+        .into()
+    }
+
+    /// Applies the zebra stripe and gridline settings to a table row. The stripe sits behind
+    /// the row button, so the selected row's button colour still covers it.
+    fn striped_row<'a>(
+        &self,
+        index: usize,
+        row: Element<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        let dark = self.effective_theme() == ThemeChoice::Dark;
+        let shade = |alpha: f32| if dark { Color::from_rgba(1.0, 1.0, 1.0, alpha) } else { Color::from_rgba(0.0, 0.0, 0.0, alpha) };
+        let stripe = (self.settings.zebra_striping && index % 2 == 1).then(|| shade(0.05));
+        let row = Container::<Message, Theme, Renderer>::new(row)
+            .width(Length::Fill)
+            .style(move |_theme: &Theme| container::Appearance {
+                background: stripe.map(iced::Background::Color),
+                ..Default::default()
+            });
+        if !self.settings.row_gridlines {
+            return row.into();
+        }
+        let line = shade(0.12);
+        column![
+            row,
+            Container::<Message, Theme, Renderer>::new(Space::with_height(1.0))
+                .width(Length::Fill)
+                .style(move |_theme: &Theme| container::Appearance {
+                    background: Some(iced::Background::Color(line)),
+                    ..Default::default()
+                }),
+        ]
+        .into()
+    }
+
+    fn view_memory_cell(&self, memory: u64, max_memory: u64, width: Length) -> Element<'_, Message, Theme, Renderer> {
+        let value = text(self.format_memory(memory));
+        if !self.settings.show_memory_bars {
+            return value.width(width).into();
         }
+        let share = if max_memory > 0 { memory as f32 / max_memory as f32 } else { 0.0 };
+        row![
+            progress_bar(0.0..=1.0, share)
+                .width(Length::Fixed(60.0))
+                .height(Length::Fixed(8.0)),
+            value,
+        ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .width(width)
+        .into()
+    }
+
+    /// Collapsible summary row for a process group, showing summed CPU and memory.
+    fn view_group_header(&self, group: &ProcessGroup<'_>, expanded: bool, narrow: bool) -> Element<'_, Message, Theme, Renderer> {
+        let header = self.visible_columns().fold(row![], |row, column| {
+            let width = self.column_width(column, narrow);
+            let cell: Element<'_, Message, Theme, Renderer> = match column {
+                SortColumn::Pid => text(if expanded { "▼" } else { "▶" }).width(width).into(),
+                SortColumn::Name => text(format!("{} ({})", group.label, group.members.len())).width(width).into(),
+                SortColumn::Cpu => text(format!("{:.1}", group.cpu_usage)).width(width).into(),
+                SortColumn::Memory => text(self.format_memory(group.memory)).width(width).into(),
+                SortColumn::Swap
+                | SortColumn::DiskRead
+                | SortColumn::DiskWrite
+                | SortColumn::StartTime
+                | SortColumn::User
+                | SortColumn::Status
+                | SortColumn::RunTime => Space::with_width(width).into(),
+            };
+            row.push(cell)
+        })
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .padding(5);
+
+        let toggle = Button::new(header)
+            .on_press(Message::GroupToggled(group.key.clone()))
+            .style(iced::theme::Button::Secondary)
+            .width(Length::Fill);
+        let pids = group.members.iter().map(|process| process.pid).collect();
+        let read_only = self.is_read_only();
+        let kill_all = Button::new(text(if read_only { "🔒 Kill all" } else { "Kill all" }))
+            .on_press_maybe((!read_only).then(|| Message::KillGroupRequested(group.label.clone(), pids)))
+            .style(if read_only { iced::theme::Button::Secondary } else { iced::theme::Button::Destructive })
+            .padding(5);
+        row![toggle, kill_all].spacing(5).align_items(Alignment::Center).into()
+    }
+
+    fn view_process_graph<'a>(&'a self, graph: &'a ProcessGraphWindow) -> Element<'a, Message, Theme, Renderer> {
+        let latest_cpu = graph.cpu_history.back().copied().unwrap_or(0.0);
+        let latest_mem = graph.mem_history.back().copied().unwrap_or(0.0);
+        let cpu_max = graph.cpu_history.iter().copied().fold(100.0, f32::max);
+        let mem_max = graph.mem_history.iter().copied().fold(1.0, f32::max) * 1.1;
+
+        let status: Element<'_, Message, Theme, Renderer> = if graph.ended {
+            text("Process ended — graph frozen").style(Color::from_rgb(0.9, 0.3, 0.3)).into()
+        } else {
+            text("🟢 Live").style(Color::from_rgb(0.3, 0.9, 0.3)).into()
+        };
+
+        column![
+            row![
+                text(format!("{} (PID: {})", graph.name, graph.pid)).size(24),
+                Space::with_width(Length::Fill),
+                status,
+            ]
+            .align_items(Alignment::Center),
+            text(format!("CPU: {:.1} %", latest_cpu)),
+            Canvas::new(LineChart::new(&graph.cpu_history, GRAPH_HISTORY_LEN, cpu_max, Color::from_rgb(0.3, 0.6, 0.9)))
+                .width(Length::Fill)
+                .height(Length::FillPortion(1)),
+            text(format!("Memory: {}", self.format_memory((latest_mem as f64 * 1024.0 * 1024.0) as u64))),
+            Canvas::new(LineChart::new(&graph.mem_history, GRAPH_HISTORY_LEN, mem_max, Color::from_rgb(0.6, 0.4, 0.9)))
+                .width(Length::Fill)
+                .height(Length::FillPortion(1)),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+
+    fn view_auto_export_settings(&self) -> Element<'_, Message, Theme, Renderer> {
+        let interval_radios = AUTO_EXPORT_INTERVAL_OPTIONS.iter().fold(
+            row![text("Every:")].spacing(15).align_items(Alignment::Center),
+            |row, &mins| {
+                row.push(Radio::new(
+                    format!("{} min", mins),
+                    mins,
+                    Some(self.settings.auto_export_interval_mins),
+                    Message::AutoExportIntervalChanged,
+                ))
+            },
+        );
+        let format = Some(self.settings.auto_export_format);
+        let retention_files_radios = EXPORT_RETENTION_FILES_OPTIONS.iter().fold(
+            row![text("Keep at most:")].spacing(15).align_items(Alignment::Center),
+            |row, &max_files| {
+                row.push(Radio::new(
+                    format!("{} files", self.format_number(max_files as f64, 0)),
+                    max_files,
+                    Some(self.settings.export_retention_max_files),
+                    Message::ExportRetentionFilesChanged,
+                ))
+            },
+        );
+        let retention_days_radios = EXPORT_RETENTION_DAYS_OPTIONS.iter().fold(
+            row![text("Delete after:")].spacing(15).align_items(Alignment::Center),
+            |row, &days| {
+                row.push(Radio::new(
+                    format!("{} days", days),
+                    days,
+                    Some(self.settings.export_retention_max_age_days),
+                    Message::ExportRetentionDaysChanged,
+                ))
+            },
+        );
+
+        column![
+            text("Scheduled Export").size(20),
+            checkbox("Export the process list automatically", self.settings.auto_export_enabled)
+                .on_toggle(Message::AutoExportToggled),
+            interval_radios,
+            row![
+                text("Format:"),
+                Radio::new("CSV", ExportFormat::Csv, format, Message::AutoExportFormatChanged),
+                Radio::new("JSON", ExportFormat::Json, format, Message::AutoExportFormatChanged),
+            ]
+            .spacing(15)
+            .align_items(Alignment::Center),
+            row![
+                text("Output folder:"),
+                text_input(&self.auto_export_dir().to_string_lossy(), &self.settings.auto_export_dir)
+                    .on_input(Message::AutoExportDirChanged)
+                    .on_submit(Message::AutoExportDirSubmitted)
+                    .width(Length::Fixed(400.0)),
+                text("(press Enter to save)").size(14),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            retention_files_radios,
+            retention_days_radios,
+        ]
+        .spacing(10)
+        .into()
+    }
 
-        let tabs = row![
-            create_tab_button("Dashboard", Tab::Dashboard, self.active_tab),
-            create_tab_button("Processes", Tab::Processes, self.active_tab),
-            create_tab_button("Settings", Tab::Settings, self.active_tab),
+    fn view_accent_settings(&self) -> Element<'_, Message, Theme, Renderer> {
+        let mut section = column![
+            checkbox("Custom accent color", self.settings.accent.is_some())
+                .on_toggle(Message::AccentToggled),
         ]
         .spacing(10);
+        if let Some(accent) = self.settings.accent {
+            for (channel, label) in ["Red", "Green", "Blue"].into_iter().enumerate() {
+                section = section.push(
+                    row![
+                        text(label).width(Length::Fixed(140.0)),
+                        slider(0.0..=1.0, accent[channel], move |value| Message::AccentChannelChanged(channel, value))
+                            .step(1.0 / 255.0)
+                            .width(Length::Fixed(300.0)),
+                        text(format!("{:.0}", accent[channel] * 255.0)),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                );
+            }
+            let [r, g, b] = accent;
+            section = section.push(
+                row![
+                    create_badge("Preview", Color::from_rgb(r, g, b)),
+                    text(format!("#{:02X}{:02X}{:02X}", (r * 255.0).round() as u8, (g * 255.0).round() as u8, (b * 255.0).round() as u8)),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center),
+            );
+        }
+        section.into()
+    }
+
+    fn view_usage_alert_settings(&self) -> Element<'_, Message, Theme, Renderer> {
+        let threshold_row = |label: &str, value: f32, on_change: fn(f32) -> Message| {
+            row![
+                text(label).width(Length::Fixed(140.0)),
+                slider(ALERT_THRESHOLD_RANGE, value, on_change)
+                    .step(5.0)
+                    .on_release(Message::AlertThresholdReleased)
+                    .width(Length::Fixed(300.0)),
+                text(format!("{:.0}%", value)),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center)
+        };
+        column![
+            text("Usage Alerts").size(20),
+            checkbox(
+                "Notify me when system CPU or memory stays high",
+                self.settings.usage_alerts_enabled,
+            )
+            .on_toggle(Message::UsageAlertsToggled),
+            threshold_row("CPU above:", self.settings.cpu_alert_threshold, Message::CpuAlertThresholdChanged),
+            threshold_row("Memory above:", self.settings.mem_alert_threshold, Message::MemAlertThresholdChanged),
+            text(format!(
+                "Fires after {} samples in a row over the threshold, at most once every {} min per metric.",
+                ALERT_SUSTAIN_SAMPLES,
+                ALERT_COOLDOWN.as_secs() / 60,
+            ))
+            .size(14),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_auto_rules(&self) -> Element<'_, Message, Theme, Renderer> {
+        let rules = self.settings.auto_rules.iter().enumerate().fold(
+            column![].spacing(5),
+            |col, (index, rule)| {
+                col.push(
+                    row![
+                        text(rule.describe()).width(Length::Fill),
+                        Button::new(text("Remove"))
+                            .on_press(Message::RemoveAutoRule(index))
+                            .style(iced::theme::Button::Secondary)
+                            .padding(5),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+
+        let draft = &self.rule_draft;
+        let form = row![
+            text_input("Name contains", &draft.name_pattern)
+                .on_input(|v| Message::RuleDraftChanged(RuleDraftField::NamePattern(v)))
+                .width(Length::Fixed(180.0)),
+            Radio::new("CPU %", RuleMetric::Cpu, Some(draft.metric), |m| {
+                Message::RuleDraftChanged(RuleDraftField::Metric(m))
+            }),
+            Radio::new("Memory MiB", RuleMetric::Memory, Some(draft.metric), |m| {
+                Message::RuleDraftChanged(RuleDraftField::Metric(m))
+            }),
+            text_input("Threshold", &draft.threshold)
+                .on_input(|v| Message::RuleDraftChanged(RuleDraftField::Threshold(v)))
+                .width(Length::Fixed(100.0)),
+            text_input("Seconds", &draft.duration_secs)
+                .on_input(|v| Message::RuleDraftChanged(RuleDraftField::Duration(v)))
+                .width(Length::Fixed(80.0)),
+            Radio::new("Alert", RuleAction::Alert, Some(draft.action), |a| {
+                Message::RuleDraftChanged(RuleDraftField::Action(a))
+            }),
+            Radio::new("Kill", RuleAction::Kill, Some(draft.action), |a| {
+                Message::RuleDraftChanged(RuleDraftField::Action(a))
+            }),
+            Button::new(text("Add rule"))
+                .on_press(Message::AddAutoRule)
+                .padding(5),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        column![
+            text("Automatic Rules").size(20),
+            checkbox("Enable automatic rules (every action is logged)", self.settings.auto_rules_enabled)
+                .on_toggle(Message::AutoRulesEnableRequested),
+            rules,
+            form,
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_settings(&self) -> Element<'_, Message, Theme, Renderer> {
+        let light_radio = Radio::new(
+            self.tr(UiText::LightTheme),
+            ThemeChoice::Light,
+            Some(self.settings.theme),
+            Message::ThemeChanged,
+        );
+        
+        let dark_radio = Radio::new(
+            self.tr(UiText::DarkTheme),
+            ThemeChoice::Dark,
+            Some(self.settings.theme),
+            Message::ThemeChanged,
+        );
+
+        let system_radio = Radio::new(
+            self.tr(UiText::SystemTheme),
+            ThemeChoice::System,
+            Some(self.settings.theme),
+            Message::ThemeChanged,
+        );
+
+        let language_radios = Language::ALL.iter().fold(
+            row![text(self.tr(UiText::Language))].spacing(15).align_items(Alignment::Center),
+            |row, &language| {
+                row.push(Radio::new(
+                    language.label(),
+                    language,
+                    Some(self.settings.language),
+                    Message::LanguageChanged,
+                ))
+            },
+        );
+
+        let tooltip_delay_radios = TOOLTIP_DELAY_OPTIONS.iter().fold(
+            row![text(self.tr(UiText::HoverDelay))].spacing(15).align_items(Alignment::Center),
+            |row, &delay_ms| {
+                row.push(Radio::new(
+                    format!("{} ms", delay_ms),
+                    delay_ms,
+                    Some(self.settings.tooltip_delay_ms),
+                    Message::TooltipDelayChanged,
+                ))
+            },
+        );
+
+        let refresh_radios = REFRESH_INTERVAL_OPTIONS.iter().fold(
+            row![text(self.tr(UiText::RefreshEvery))].spacing(15).align_items(Alignment::Center),
+            |row, &secs| {
+                row.push(Radio::new(
+                    format!("{} s", secs),
+                    secs,
+                    Some(self.settings.refresh_interval_secs),
+                    Message::RefreshIntervalChanged,
+                ))
+            },
+        );
+        let row_limit_radios = PROCESS_ROW_LIMIT_OPTIONS.iter().fold(
+            row![text(self.tr(UiText::MaxRows))].spacing(15).align_items(Alignment::Center),
+            |row, &limit| {
+                row.push(Radio::new(
+                    limit.to_string(),
+                    limit,
+                    Some(self.settings.process_row_limit),
+                    Message::ProcessRowLimitChanged,
+                ))
+            },
+        );
+
+        let grace_radios = PROCESS_GRACE_PERIOD_OPTIONS.iter().fold(
+            row![text(self.tr(UiText::KeepExitedRows))].spacing(15).align_items(Alignment::Center),
+            |row, &grace_ms| {
+                row.push(Radio::new(
+                    format!("{} ms", grace_ms),
+                    grace_ms,
+                    Some(self.settings.process_grace_period_ms),
+                    Message::ProcessGracePeriodChanged,
+                ))
+            },
+        );
+
+        let grouping_radios = NumberGrouping::ALL.iter().fold(
+            row![text(self.tr(UiText::NumberFormat))].spacing(15).align_items(Alignment::Center),
+            |row, &grouping| {
+                row.push(Radio::new(
+                    grouping.label(),
+                    grouping,
+                    Some(self.settings.number_grouping),
+                    Message::NumberGroupingChanged,
+                ))
+            },
+        );
+
+        // The last enabled column can't be switched off.
+        let only_column = self.settings.visible_columns.len() == 1;
+        let column_checkboxes = SortColumn::ALL.iter().fold(
+            row![text(self.tr(UiText::Columns))].spacing(15).align_items(Alignment::Center),
+            |row, &column| {
+                let visible = self.settings.visible_columns.contains(&column);
+                row.push(
+                    checkbox(column.label(), visible)
+                        .on_toggle_maybe((!(visible && only_column)).then_some(move |on| {
+                            Message::ColumnVisibilityToggled(column, on)
+                        })),
+                )
+            },
+        );
+
+        let tree_depth_radios = TREE_DEPTH_LIMIT_OPTIONS.iter().fold(
+            row![text(self.tr(UiText::TreeDepthLimit))].spacing(15).align_items(Alignment::Center),
+            |row, &depth| {
+                row.push(Radio::new(
+                    self.tr(UiText::TreeLevels).replacen("{}", &depth.to_string(), 1),
+                    depth,
+                    Some(self.settings.tree_depth_limit),
+                    Message::TreeDepthLimitChanged,
+                ))
+            },
+        );
+
+        let kill_confirm_radios = KillConfirmMode::ALL.iter().fold(
+            row![text(self.tr(UiText::ConfirmBeforeKilling))].spacing(15).align_items(Alignment::Center),
+            |row, &mode| {
+                row.push(Radio::new(
+                    mode.label(),
+                    mode,
+                    Some(self.settings.kill_confirm_mode),
+                    Message::KillConfirmModeChanged,
+                ))
+            },
+        );
+
+        let memory_metric = Some(self.settings.memory_metric);
+        let memory_metric_radios = row![
+            text(self.tr(UiText::DashboardMemory)),
+            Radio::new(self.tr(UiText::MemoryUsed), MemoryMetric::Used, memory_metric, Message::MemoryMetricChanged),
+            Radio::new(
+                self.tr(UiText::MemoryTotalMinusAvailable),
+                MemoryMetric::TotalMinusAvailable,
+                memory_metric,
+                Message::MemoryMetricChanged,
+            ),
+        ]
+        .spacing(15)
+        .align_items(Alignment::Center);
+        let memory_unit_radios = MemoryUnit::ALL.iter().fold(
+            row![text(self.tr(UiText::MemoryUnit))].spacing(15).align_items(Alignment::Center),
+            |row, &unit| {
+                row.push(Radio::new(unit.label(), unit, Some(self.settings.memory_unit), Message::MemoryUnitChanged))
+            },
+        );
+
+        let profile_names: Vec<String> = self.all_profiles().into_iter().map(|p| p.name).collect();
+        let has_user_profile = self.active_user_profile().is_some();
+        let profiles_row = row![
+            text(self.tr(UiText::Profile)),
+            iced::widget::pick_list(profile_names, self.settings.active_profile.clone(), Message::ProfileSelected)
+                .placeholder(self.tr(UiText::ChooseProfile))
+                .width(Length::Fixed(200.0)),
+            text_input(self.tr(UiText::ProfileName), &self.profile_name_input)
+                .on_input(Message::ProfileNameInputChanged)
+                .on_submit(Message::SaveProfileRequested)
+                .width(Length::Fixed(200.0)),
+            Button::new(text(self.tr(UiText::SaveAsProfile)))
+                .on_press(Message::SaveProfileRequested)
+                .padding(10),
+            Button::new(text(self.tr(UiText::Rename)))
+                .on_press_maybe(has_user_profile.then_some(Message::RenameProfileRequested))
+                .style(iced::theme::Button::Secondary)
+                .padding(10),
+            Button::new(text(self.tr(UiText::Delete)))
+                .on_press_maybe(has_user_profile.then_some(Message::DeleteProfileRequested))
+                .style(iced::theme::Button::Destructive)
+                .padding(10),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+        Container::<Message, Theme, Renderer>::new(Scrollable::new(
+            column![
+                text(self.tr(UiText::ApplicationSettings)).size(24),
+                Space::with_height(20),
+                profiles_row,
+                Space::with_height(20),
+                language_radios,
+                light_radio,
+                dark_radio,
+                system_radio,
+                self.view_accent_settings(),
+                checkbox(self.tr(UiText::CompactMode), self.settings.compact_mode)
+                    .on_toggle(Message::CompactModeToggled),
+                Space::with_height(20),
+                text(self.tr(UiText::RefreshSection)).size(20),
+                refresh_radios,
+                Space::with_height(20),
+                text(self.tr(UiText::NumbersSection)).size(20),
+                grouping_radios,
+                memory_metric_radios,
+                memory_unit_radios,
+                Space::with_height(20),
+                text(self.tr(UiText::ProcessTableSection)).size(20),
+                row_limit_radios,
+                grace_radios,
+                tree_depth_radios,
+                column_checkboxes,
+                checkbox(self.tr(UiText::CapProcessCpu), self.settings.cap_process_cpu)
+                    .on_toggle(Message::CpuCapToggled),
+                checkbox(self.tr(UiText::ShowMemoryBars), self.settings.show_memory_bars)
+                    .on_toggle(Message::MemoryBarsToggled),
+                checkbox(self.tr(UiText::ZebraStriping), self.settings.zebra_striping)
+                    .on_toggle(Message::ZebraStripingToggled),
+                checkbox(self.tr(UiText::RowGridlines), self.settings.row_gridlines)
+                    .on_toggle(Message::RowGridlinesToggled),
+                Space::with_height(20),
+                text(self.tr(UiText::ProcessTooltipsSection)).size(20),
+                tooltip_delay_radios,
+                checkbox(self.tr(UiText::ShowName), self.settings.tooltip_show_name)
+                    .on_toggle(|enabled| Message::TooltipFieldToggled(TooltipField::Name, enabled)),
+                checkbox(self.tr(UiText::ShowExe), self.settings.tooltip_show_exe)
+                    .on_toggle(|enabled| Message::TooltipFieldToggled(TooltipField::Exe, enabled)),
+                checkbox(self.tr(UiText::ShowCmd), self.settings.tooltip_show_cmd)
+                    .on_toggle(|enabled| Message::TooltipFieldToggled(TooltipField::Cmd, enabled)),
+                Space::with_height(20),
+                text(self.tr(UiText::SafetySection)).size(20),
+                checkbox(self.tr(UiText::ReadOnlyMode), self.read_only_forced || self.settings.read_only)
+                .on_toggle_maybe((!self.read_only_forced).then_some(Message::ReadOnlyToggled)),
+                if self.read_only_forced {
+                    text(self.tr(UiText::ReadOnlyForced)).size(14)
+                } else {
+                    text("")
+                },
+                kill_confirm_radios,
+                Space::with_height(20),
+                self.view_usage_alert_settings(),
+                Space::with_height(20),
+                self.view_auto_rules(),
+                Space::with_height(20),
+                self.view_auto_export_settings(),
+                Space::with_height(20),
+                text(self.tr(UiText::ScreenshotsSection)).size(20),
+                row![
+                    text(self.tr(UiText::SaveFolder)),
+                    text_input(self.tr(UiText::PicturesFolder), &self.settings.screenshot_dir)
+                        .on_input(Message::ScreenshotDirChanged)
+                        .on_submit(Message::ScreenshotDirSubmitted)
+                        .width(Length::Fixed(400.0)),
+                    text(self.tr(UiText::PressEnterToSave)).size(14),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center),
+                Space::with_height(20),
+                text(self.tr(UiText::WindowSection)).size(20),
+                checkbox(self.tr(UiText::RememberWindowSize), self.settings.remember_window_size_per_tab)
+                    .on_toggle(Message::RememberWindowSizeToggled),
+                checkbox(self.tr(UiText::LiveWindowTitle), self.settings.live_window_title)
+                    .on_toggle(Message::LiveWindowTitleToggled),
+                // Only offered when there is a tray icon to bring the window back from.
+                column![].push_maybe(self.has_tray().then(|| {
+                    checkbox(self.tr(UiText::StartInTray), self.settings.start_in_tray)
+                        .on_toggle(Message::StartInTrayToggled)
+                })),
+                Space::with_height(20),
+                text(self.tr(UiText::AccessibilitySection)).size(20),
+                checkbox(self.tr(UiText::ReduceMotion), self.settings.reduce_motion)
+                    .on_toggle(Message::ReduceMotionToggled),
+                checkbox(self.tr(UiText::ShowGraphs), self.settings.show_graphs)
+                    .on_toggle(Message::GraphsToggled),
+                Space::with_height(20),
+                text(self.tr(UiText::BackgroundSection)).size(20),
+                checkbox(self.tr(UiText::BackgroundSampling), self.settings.background_sampling)
+                .on_toggle(Message::BackgroundSamplingToggled),
+                Space::with_height(20),
+                text(self.tr(UiText::MetricsEndpointSection)).size(20),
+                checkbox(self.tr(UiText::ServeMetrics), self.settings.metrics_endpoint_enabled)
+                    .on_toggle(Message::MetricsEndpointToggled),
+                row![
+                    text(self.tr(UiText::Port)),
+                    text_input("9184", &self.metrics_port_input)
+                        .on_input(Message::MetricsPortInputChanged)
+                        .on_submit(Message::MetricsPortSubmitted)
+                        .width(Length::Fixed(100.0)),
+                    text(self.tr(UiText::MetricsUrls).replacen("{}", &self.settings.metrics_port.to_string(), 1)).size(14),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center),
+                Space::with_height(20),
+                self.view_detail_action_settings(),
+                Space::with_height(20),
+                text(self.tr(UiText::DeveloperToolsSection)).size(20),
+                checkbox(self.tr(UiText::EnableDeveloperActions), self.settings.developer_tools_enabled)
+                    .on_toggle(Message::DeveloperToolsToggled),
+                row![
+                    text(self.tr(UiText::DebuggerCommand)),
+                    text_input(default_debugger_command(), &self.settings.debugger_command)
+                        .on_input(Message::DebuggerCommandChanged)
+                        .on_submit(Message::DebuggerCommandSubmitted)
+                        .width(Length::Fixed(400.0)),
+                    text(self.tr(UiText::DebuggerCommandHint)).size(14),
+                ]
+                .spacing(10)
+                .align_items(Alignment::Center),
+                Space::with_height(20),
+                text(self.tr(UiText::ShareSettingsSection)).size(20),
+                row![
+                    Button::new(text(self.tr(UiText::CopySettings)))
+                        .on_press(Message::CopySettingsRequested)
+                        .padding(10),
+                    Button::new(text(self.tr(UiText::PasteSettings)))
+                        .on_press(Message::PasteSettingsRequested)
+                        .style(iced::theme::Button::Secondary)
+                        .padding(10),
+                    Button::new(text(self.tr(UiText::CopyDiagnostics)))
+                        .on_press(Message::CopyDiagnostics)
+                        .style(iced::theme::Button::Secondary)
+                        .padding(10),
+                ]
+                .spacing(10),
+                text(self.rebuild_timing.summary()).size(14),
+            ]
+            .spacing(10)
+            .padding(20)
+            .width(Length::Fill)
+        ))
+        .width(Length::Fixed(1200.0))
+        .height(Length::Fixed(600.0))
+        .align_x(alignment::Horizontal::Left)
+        .style(iced::theme::Container::Box)
+        .into()
+    }
+}
+
+/// Sends SIGKILL directly so a failure can report the OS reason instead of just `false`.
+#[cfg(unix)]
+fn kill_process(process: &sysinfo::Process) -> Result<(), String> {
+    let Ok(raw_pid) = libc::pid_t::try_from(process.pid().as_u32()) else {
+        return Err("invalid PID".to_string());
+    };
+    // SAFETY: kill(2) only reads its two integer arguments.
+    if unsafe { libc::kill(raw_pid, libc::SIGKILL) } == 0 {
+        Ok(())
+    } else {
+        Err(describe_os_error(&std::io::Error::last_os_error()))
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process(process: &sysinfo::Process) -> Result<(), String> {
+    if process.kill() {
+        Ok(())
+    } else {
+        Err("the OS refused, possibly insufficient permissions".to_string())
+    }
+}
+
+/// Stops (SIGSTOP) or continues (SIGCONT) a process.
+#[cfg(unix)]
+fn suspend_process(pid: Pid, suspend: bool) -> Result<(), String> {
+    let Ok(raw_pid) = libc::pid_t::try_from(pid.as_u32()) else {
+        return Err("invalid PID".to_string());
+    };
+    let signal = if suspend { libc::SIGSTOP } else { libc::SIGCONT };
+    // SAFETY: kill(2) only reads its two integer arguments.
+    if unsafe { libc::kill(raw_pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(describe_os_error(&std::io::Error::last_os_error()))
+    }
+}
+
+#[cfg(not(unix))]
+fn suspend_process(_pid: Pid, _suspend: bool) -> Result<(), String> {
+    Err("not supported on this platform".to_string())
+}
+
+/// Niceness after moving `current` by `delta`, kept within the -20..=19 range the kernel accepts.
+fn nice_after(current: i32, delta: i32) -> i32 {
+    current.saturating_add(delta).clamp(-20, 19)
+}
+
+#[cfg(unix)]
+fn process_nice(pid: Pid) -> Result<i32, String> {
+    // getpriority(2) can legitimately return -1, so errno is cleared first and checked after.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    // SAFETY: the errno location is a valid thread-local pointer.
+    unsafe { *libc::__errno_location() = 0 };
+    #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+    // SAFETY: as above.
+    unsafe { *libc::__error() = 0 };
+    // SAFETY: getpriority(2) only reads its integer arguments.
+    let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS as _, pid.as_u32() as libc::id_t) };
+    let error = std::io::Error::last_os_error();
+    if nice == -1 && error.raw_os_error().is_some_and(|code| code != 0) {
+        Err(describe_os_error(&error))
+    } else {
+        Ok(nice)
+    }
+}
+
+#[cfg(not(unix))]
+fn process_nice(_pid: Pid) -> Result<i32, String> {
+    Err("not supported on this platform".to_string())
+}
+
+/// Moves the niceness of `pid` by `delta`, returning the new value.
+#[cfg(unix)]
+fn adjust_process_nice(pid: Pid, delta: i32) -> Result<i32, String> {
+    let nice = nice_after(process_nice(pid)?, delta);
+    // SAFETY: setpriority(2) only reads its integer arguments.
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS as _, pid.as_u32() as libc::id_t, nice) } == 0 {
+        Ok(nice)
+    } else {
+        Err(describe_os_error(&std::io::Error::last_os_error()))
+    }
+}
+
+/// Windows priority classes are out of scope for now: they don't map onto a niceness step,
+/// so the Renice buttons report the platform as unsupported there.
+#[cfg(not(unix))]
+fn adjust_process_nice(_pid: Pid, _delta: i32) -> Result<i32, String> {
+    Err("not supported on this platform".to_string())
+}
+
+/// Whether the current user may renice `process` at all: root, or its owner. Lowering
+/// niceness can still fail for owners, which is reported when the button is pressed.
+#[cfg(unix)]
+fn can_adjust_priority(process: &sysinfo::Process) -> bool {
+    // SAFETY: geteuid(2) has no arguments and cannot fail.
+    let euid = unsafe { libc::geteuid() };
+    euid == 0 || process.effective_user_id().or(process.user_id()).is_none_or(|uid| **uid == euid)
+}
+
+#[cfg(not(unix))]
+fn can_adjust_priority(_process: &sysinfo::Process) -> bool {
+    false
+}
+
+/// Opens a folder in the platform file manager.
+fn open_folder(folder: &std::path::Path) -> Result<(), String> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(opener)
+        .arg(folder)
+        .spawn()
+        .map(reap_in_background)
+        .map_err(|e| e.to_string())
+}
+
+/// Shows a native desktop notification. Delivery can block on the notification daemon, so
+/// it happens on a helper thread and failures are only logged.
+fn show_desktop_notification(summary: &str, body: &str) {
+    let (summary, body) = (summary.to_string(), body.to_string());
+    std::thread::spawn(move || {
+        let result = notify_rust::Notification::new()
+            .appname("System Monitor")
+            .summary(&summary)
+            .body(&body)
+            .show();
+        if let Err(e) = result {
+            tracing::error!("Failed to show desktop notification: {}", e);
+        }
+    });
+}
+
+fn signal_name(signal: Signal) -> &'static str {
+    match signal {
+        Signal::Term => "SIGTERM",
+        Signal::Kill => "SIGKILL",
+        Signal::Hangup => "SIGHUP",
+        Signal::Stop => "SIGSTOP",
+        Signal::Continue => "SIGCONT",
+        _ => "signal",
+    }
+}
+
+/// One-line explanation shown in the signal confirmation dialog.
+fn signal_effect(signal: Signal) -> &'static str {
+    match signal {
+        Signal::Term => "Asks the process to exit; it may clean up first or ignore the request.",
+        Signal::Kill => "Ends the process immediately. This action cannot be undone.",
+        Signal::Hangup => "Many daemons reload their configuration; other programs exit.",
+        Signal::Stop => "Pauses the process until it receives SIGCONT.",
+        Signal::Continue => "Resumes a stopped process.",
+        _ => "Sends the signal to the process.",
+    }
+}
+
+/// Number of entries in `/proc/<pid>/fd`; `None` when it can't be read, e.g. for another
+/// user's process.
+#[cfg(target_os = "linux")]
+fn open_fd_count(pid: Pid) -> Option<usize> {
+    std::fs::read_dir(format!("/proc/{}/fd", pid)).ok().map(|entries| entries.count())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_fd_count(_pid: Pid) -> Option<usize> {
+    None
+}
+
+/// Reads every NVIDIA GPU; devices that fail to answer are skipped.
+#[cfg(feature = "gpu")]
+fn read_gpus(nvml: &nvml_wrapper::Nvml) -> Vec<GpuData> {
+    let count = nvml.device_count().unwrap_or(0);
+    (0..count)
+        .filter_map(|index| {
+            let device = nvml.device_by_index(index).ok()?;
+            let memory = device.memory_info().ok()?;
+            Some(GpuData {
+                name: device.name().unwrap_or_else(|_| format!("GPU {}", index)),
+                utilization_percent: device.utilization_rates().map_or(0, |rates| rates.gpu),
+                memory_used: memory.used,
+                memory_total: memory.total,
+            })
+        })
+        .collect()
+}
+
+/// Whether the OS prefers a dark UI. Dark when it doesn't say or detection fails.
+fn system_prefers_dark() -> bool {
+    dark_light::detect() != dark_light::Mode::Light
+}
+
+/// Keeps the tray icon alive; dropping it removes the icon.
+#[cfg(feature = "tray")]
+struct Tray {
+    /// On Linux the icon lives on its own GTK thread instead, see `start_tray`.
+    #[cfg(not(target_os = "linux"))]
+    _icon: tray_icon::TrayIcon,
+}
+
+#[cfg(feature = "tray")]
+fn build_tray_icon() -> Result<tray_icon::TrayIcon, String> {
+    use tray_icon::menu::{Menu, MenuItem};
+    let menu = Menu::new();
+    menu.append_items(&[
+        &MenuItem::with_id(TRAY_TOGGLE_ID, "Show/Hide window", true, None),
+        &MenuItem::with_id(TRAY_QUIT_ID, "Quit", true, None),
+    ])
+    .map_err(|e| e.to_string())?;
+    tray_icon::TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip("System Monitor")
+        .with_icon(tray_icon_image()?)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// A filled circle in the default accent blue, so no image file has to ship with the binary.
+#[cfg(feature = "tray")]
+fn tray_icon_image() -> Result<tray_icon::Icon, String> {
+    const SIZE: u32 = 32;
+    let radius = SIZE as f32 / 2.0;
+    let rgba = (0..SIZE * SIZE)
+        .flat_map(|i| {
+            let (x, y) = ((i % SIZE) as f32 + 0.5 - radius, (i / SIZE) as f32 + 0.5 - radius);
+            let alpha = if x * x + y * y <= radius * radius { 255 } else { 0 };
+            [40, 120, 220, alpha]
+        })
+        .collect();
+    tray_icon::Icon::from_rgba(rgba, SIZE, SIZE).map_err(|e| e.to_string())
+}
+
+/// Linux trays need a GTK main loop on the thread that owns the icon, which iced doesn't
+/// run, so the icon gets a thread of its own that lives as long as the app.
+#[cfg(all(feature = "tray", target_os = "linux"))]
+fn start_tray() -> Result<Tray, String> {
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        if let Err(e) = gtk::init() {
+            let _ = ready_tx.send(Err(e.to_string()));
+            return;
+        }
+        match build_tray_icon() {
+            Ok(_icon) => {
+                let _ = ready_tx.send(Ok(()));
+                gtk::main();
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(e));
+            }
+        }
+    });
+    ready_rx.recv().map_err(|e| e.to_string())??;
+    Ok(Tray {})
+}
+
+#[cfg(all(feature = "tray", not(target_os = "linux")))]
+fn start_tray() -> Result<Tray, String> {
+    build_tray_icon().map(|icon| Tray { _icon: icon })
+}
+
+/// Sends `signal` through sysinfo, reporting signals the platform doesn't have.
+fn send_signal(process: &sysinfo::Process, signal: Signal) -> Result<(), String> {
+    match process.kill_with(signal) {
+        None => Err(format!("{} is not supported on this platform", signal_name(signal))),
+        Some(true) => Ok(()),
+        #[cfg(unix)]
+        Some(false) => Err(describe_os_error(&std::io::Error::last_os_error())),
+        #[cfg(not(unix))]
+        Some(false) => Err("the OS refused, possibly insufficient permissions".to_string()),
+    }
+}
 
-        let page_content = match self.active_tab {
-            Tab::Dashboard => self.view_dashboard(),
-            Tab::Processes => self.view_processes(),
-            Tab::Settings => self.view_settings(),
-        };
-        
-        let status_bar: Element<'_, Message, Theme, Renderer> = if let Some(status) = &self.last_status_message {
-            let (bg_color, text_color) = match status.level {
-                NotificationLevel::Success => (Color::from_rgb(0.2, 0.6, 0.2), Color::WHITE),
-                NotificationLevel::Error => (Color::from_rgb(0.8, 0.2, 0.2), Color::WHITE),
-            };
-            Container::<Message, Theme, Renderer>::new(
-                text(status.message.clone())
-                    .style(iced::theme::Text::Color(text_color))
-                    .horizontal_alignment(alignment::Horizontal::Center)
-            )
-            .width(Length::Fill)
-            .padding(10)
-            .style(move |theme: &Theme| container::Appearance {
-                background: Some(iced::Background::Color(bg_color)),
-                border: Border { radius: 5.0.into(), ..Default::default() },
-                ..Default::default()
-            })
-            .into()
-        } else {
-            Container::<Message, Theme, Renderer>::new(Space::with_height(0.0))
-                .padding(10)
-                .into()
-        };
+/// Friendly wording for the errno values a signal call commonly fails with.
+#[cfg(unix)]
+fn describe_os_error(error: &std::io::Error) -> String {
+    match error.raw_os_error() {
+        Some(libc::EPERM) => "permission denied".to_string(),
+        Some(libc::ESRCH) => "no such process".to_string(),
+        Some(libc::EINVAL) => "invalid signal".to_string(),
+        Some(_) => error.to_string(),
+        None => "unknown error".to_string(),
+    }
+}
 
-        let main_content = column![
-            tabs,
-            Space::with_height(20),
-            page_content,
-            Space::with_height(10),
-            status_bar
-        ]
-        .spacing(10)
-        .padding(40)
-        .align_items(Alignment::Center);
+/// Groups processes by name or executable path, heaviest CPU group first.
+/// Processes without a known executable path share one "unknown" group.
+fn group_processes<'a>(processes: &[&'a ProcessData], mode: GroupingMode) -> Vec<ProcessGroup<'a>> {
+    let mut groups: Vec<ProcessGroup<'a>> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for &process in processes {
+        let (key, label) = match mode {
+            GroupingMode::ExePath => match &process.exe {
+                Some(exe) => (exe.clone(), exe.clone()),
+                None => (String::new(), "Unknown executable".to_string()),
+            },
+            _ => (process.name.clone(), process.name.clone()),
+        };
+        let slot = *index.entry(key.clone()).or_insert_with(|| {
+            groups.push(ProcessGroup { key, label, members: Vec::new(), cpu_usage: 0.0, memory: 0 });
+            groups.len() - 1
+        });
+        let group = &mut groups[slot];
+        group.cpu_usage += process.cpu_usage;
+        group.memory += process.memory;
+        group.members.push(process);
+    }
+    groups.sort_by(|a, b| {
+        b.cpu_usage
+            .partial_cmp(&a.cpu_usage)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    groups
+}
 
-        if let Some(pid_to_kill) = self.show_kill_confirm {
-            let process_name = self.system.process(pid_to_kill)
-                                        .map_or("Unknown Process", |p| p.name());
-            
-            Container::<Message, Theme, Renderer>::new(
-                column![
-                    Container::<Message, Theme, Renderer>::new(main_content)
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .style(|theme: &Theme| container::Appearance {
-                            background: Some(iced::Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.3))),
-                            ..Default::default()
-                        }),
-                    Container::<Message, Theme, Renderer>::new(
-                        column![
-                            text(format!("Kill Process: {} (PID: {})?", process_name, pid_to_kill)).size(24),
-                            Space::with_height(10),
-                            text("Are you sure? This action cannot be undone."),
-                            Space::with_height(20),
-                            row![
-                                Button::new(text("Cancel"))
-                                    .on_press(Message::KillProcessCancelled)
-                                    .style(iced::theme::Button::Secondary)
-                                    .padding(10),
-                                Button::new(text("Yes, Kill Process"))
-                                    .on_press(Message::KillProcessConfirmed(pid_to_kill))
-                                    .style(iced::theme::Button::Destructive)
-                                    .padding(10),
-                            ].spacing(10).align_items(Alignment::Center),
-                        ]
-                        .spacing(10)
-                        .padding(30)
-                        .align_items(Alignment::Center)
-                    )
-                    .style(|theme: &Theme| {
-                        let palette = theme.extended_palette();
-                        container::Appearance {
-                            background: Some(iced::Background::Color(palette.background.base.color)),
-                            border: Border {
-                                color: palette.background.strong.color,
-                                width: 2.0,
-                                radius: 10.0.into(),
-                            },
-                            ..Default::default()
-                        }
-                    })
-                    .width(Length::Fixed(500.0))
-                    .center_x(),
-                ]
-            )
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .center_x()
-            .center_y()
-            .into()
+/// Serves `/metrics` (Prometheus text) and `/metrics.json` on localhost until `shutdown`'s
+/// sender is dropped. Only fails when the port can't be bound.
+async fn serve_metrics(
+    port: u16,
+    snapshot: std::sync::Arc<std::sync::RwLock<MetricsSnapshot>>,
+    mut shutdown: tokio::sync::watch::Receiver<()>,
+) -> Result<(), String> {
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::AddrInUse {
+            format!("port {} is already in use", port)
         } else {
-            main_content.into()
+            e.to_string()
+        }
+    })?;
+    tracing::info!("Metrics endpoint listening on http://127.0.0.1:{}/metrics", port);
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let current = snapshot.read().map(|s| s.clone()).unwrap_or_default();
+                    tokio::spawn(respond_metrics(stream, current));
+                }
+                Err(e) => tracing::warn!("Metrics endpoint accept failed: {}", e),
+            },
+            _ = shutdown.changed() => {
+                tracing::info!("Metrics endpoint on port {} stopped", port);
+                return Ok(());
+            }
         }
     }
 }
 
-impl App {
-    fn build_process_list(sys: &System) -> Vec<ProcessData> {
-        let mut processes: Vec<ProcessData> = sys
-            .processes()
-            .values()
-            .map(|p| ProcessData {
-                pid: p.pid(),
-                name: p.name().to_string(),
-                cpu_usage: p.cpu_usage(),
-                memory: p.memory(),
-            })
-            .collect();
-        processes.sort_by(|a, b| {
-            b.cpu_usage
-                .partial_cmp(&a.cpu_usage)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        processes
+async fn respond_metrics(mut stream: tokio::net::TcpStream, snapshot: MetricsSnapshot) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let mut buffer = [0u8; 1024];
+    let Ok(Ok(read)) = tokio::time::timeout(METRICS_READ_TIMEOUT, stream.read(&mut buffer)).await else {
+        return;
+    };
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let path = request.lines().next().and_then(|line| line.split_whitespace().nth(1)).unwrap_or("");
+    let (status, content_type, body) = match path {
+        "/metrics" => ("200 OK", "text/plain; version=0.0.4", snapshot.to_prometheus()),
+        "/metrics.json" => (
+            "200 OK",
+            "application/json",
+            serde_json::to_string(&snapshot).unwrap_or_default(),
+        ),
+        _ => ("404 Not Found", "text/plain", "not found\n".to_string()),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::warn!("Failed to write metrics response: {}", e);
     }
+}
 
-    async fn save_csv(process_list: Vec<ProcessExportData>) -> Result<String, String> {
-        let path_buf = ProjectDirs::from("com", "YourOrg", "SystemMonitor")
-            .map(|dirs| dirs.data_local_dir().join("processes_export.csv"))
-            .unwrap_or_else(|| PathBuf::from("processes_export.csv")); // Fallback
+/// Waits on a spawned child from a helper thread so it doesn't linger as a zombie once it exits.
+fn reap_in_background(mut child: std::process::Child) {
+    std::thread::spawn(move || child.wait());
+}
 
-        let path_str = path_buf.to_string_lossy().to_string();
+/// Plain-text support bundle: host, current usage, the busiest processes and the settings
+/// with paths redacted. Missing values are written as "unknown" rather than left out.
+fn diagnostics_report(
+    os: &OsInfo,
+    data: &SystemData,
+    processes: &[ProcessData],
+    rebuild_timing: &RebuildTiming,
+    settings: &AppSettings,
+) -> String {
+    let or_unknown = |value: &Option<String>| value.clone().unwrap_or_else(|| "unknown".to_string());
+    let mut report = format!("System Monitor {} diagnostics\n\n", APP_VERSION);
+    report += &format!(
+        "OS: {} {}\nKernel: {}\nCPU: {} ({} cores)\n\n",
+        or_unknown(&os.name),
+        or_unknown(&os.os_version),
+        or_unknown(&os.kernel_version),
+        or_unknown(&os.cpu_brand),
+        os.core_count,
+    );
+    report += &format!(
+        "CPU usage: {:.1}%\nMemory: {:.2} / {:.2} GiB ({})\nSwap: {:.2} / {:.2} GiB\nProcesses: {}\n\n",
+        data.cpu_usage,
+        data.memory_used as f64 / BYTES_PER_GIB,
+        data.memory_total as f64 / BYTES_PER_GIB,
+        data.memory_metric.label().text(Language::English),
+        data.swap_used as f64 / BYTES_PER_GIB,
+        data.swap_total as f64 / BYTES_PER_GIB,
+        data.process_count,
+    );
+    report += &format!("{}\n\n", rebuild_timing.summary());
 
-        if let Some(parent) = path_buf.parent() {
-            if !parent.exists() {
-                tokio::fs::create_dir_all(parent)
-                    .await
-                    .map_err(|e| e.to_string())?;
-            }
+    let mut top: Vec<&ProcessData> = processes.iter().filter(|p| p.exited_at.is_none()).collect();
+    top.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
+    report += "Top processes by CPU:\n";
+    if top.is_empty() {
+        report += "  (none)\n";
+    }
+    for process in top.into_iter().take(DIAGNOSTICS_TOP_PROCESSES) {
+        report += &format!(
+            "  {:>7}  {:<24} {:>6.1}%  {:>8.1} MB\n",
+            process.pid,
+            process.name,
+            process.cpu_usage,
+            process.memory as f64 / (1024.0 * 1024.0),
+        );
+    }
+
+    let settings_json = settings.redacted().to_json().unwrap_or_else(|e| format!("unavailable: {}", e));
+    report += &format!("\nSettings:\n{}\n", settings_json);
+    report
+}
+
+/// Case-insensitive substring match of a process name against the table filter.
+fn name_matches_filter(name: &str, filter: &str) -> bool {
+    let filter = filter.trim();
+    filter.is_empty() || name.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// The table filter applied to a row: its name, plus its command line when `command_line` is set.
+fn process_matches_filter(row: &ProcessData, filter: &str, command_line: bool) -> bool {
+    name_matches_filter(&row.name, filter) || (command_line && name_matches_filter(&row.cmd, filter))
+}
+
+/// "Started"/"Exited" lines for PIDs that appeared in `current` or vanished since the last
+/// refresh. Names of exited processes come from the `old` rows, which still list them.
+fn process_lifecycle_events(
+    previous: &std::collections::HashSet<Pid>,
+    old: &[ProcessData],
+    current: &[ProcessData],
+    language: Language,
+) -> Vec<String> {
+    let live: std::collections::HashSet<Pid> = current.iter().map(|p| p.pid).collect();
+    let describe = |label: UiText, p: &ProcessData| {
+        label.text(language).replacen("{}", &p.name, 1).replacen("{}", &p.pid.to_string(), 1)
+    };
+    let started = current.iter()
+        .filter(|p| !previous.contains(&p.pid))
+        .map(|p| describe(UiText::EventStarted, p));
+    let exited = old.iter()
+        .filter(|p| previous.contains(&p.pid) && !live.contains(&p.pid))
+        .map(|p| describe(UiText::EventExited, p));
+    started.chain(exited).collect()
+}
+
+/// Every distinct process owner, sorted; processes without a resolved user are left out.
+fn distinct_users(processes: &[ProcessData]) -> Vec<String> {
+    let mut users: Vec<String> = processes.iter().filter_map(|p| p.user.clone()).collect();
+    users.sort();
+    users.dedup();
+    users
+}
+
+/// Counts processes by their status label ("Run", "Sleep", "Zombie", ...).
+fn count_by_status(processes: &[ProcessData]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for process in processes {
+        *counts.entry(process.status.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Whether a row reaches the table's minimum CPU % and memory (MiB); zero minimums always pass.
+fn meets_usage_minimums(row: &ProcessData, min_cpu: f32, min_mem_mib: f64) -> bool {
+    row.cpu_usage >= min_cpu && row.memory as f64 / (1024.0 * 1024.0) >= min_mem_mib
+}
+
+/// Parses a minimum filter input; empty or unparsable input means no minimum.
+fn parse_minimum(input: &str) -> f64 {
+    input.trim().parse::<f64>().ok().filter(|value| value.is_finite() && *value > 0.0).unwrap_or(0.0)
+}
+
+/// The process list row shown in the detail pane for `pid`, if it is still listed.
+fn detail_row(process_list: &[ProcessData], pid: Pid) -> Option<&ProcessData> {
+    process_list.iter().find(|p| p.pid == pid)
+}
+
+/// Whether the selected PID still belongs to the process that was selected. A PID that
+/// is listed under a different name has been recycled by the OS for another program.
+fn selection_is_current(process_list: &[ProcessData], pid: Pid, name: Option<&str>) -> bool {
+    detail_row(process_list, pid).is_some_and(|row| name.is_none_or(|name| row.name == name))
+}
+
+fn cap_cpu(raw: f32, cap: Option<f32>) -> f32 {
+    cap.map_or(raw, |cap| raw.min(cap))
+}
+
+/// Bars per row for the per-core usage grid: at most 8, with rows kept evenly filled so
+/// e.g. 12 cores lay out as 2×6 rather than 8 + 4.
+fn core_bar_columns(cores: usize) -> usize {
+    const MAX_COLUMNS: usize = 8;
+    let rows = cores.div_ceil(MAX_COLUMNS).max(1);
+    cores.div_ceil(rows).max(1)
+}
+
+/// Columns for the core heat map: roughly square, but no wider than 16 cells.
+fn heat_grid_columns(cores: usize) -> usize {
+    ((cores as f64).sqrt().ceil() as usize).clamp(1, 16)
+}
+
+/// Red once the 1-minute load exceeds the logical core count (work is queueing), amber from
+/// 70% of it, otherwise the theme's text colour.
+fn load_color(load: f64, cores: usize) -> Option<Color> {
+    let ratio = load / cores.max(1) as f64;
+    if ratio > 1.0 {
+        Some(Color::from_rgb(0.9, 0.3, 0.3))
+    } else if ratio >= 0.7 {
+        Some(Color::from_rgb(0.9, 0.7, 0.2))
+    } else {
+        None
+    }
+}
+
+#[cfg(unix)]
+fn read_load_average() -> Option<[f64; 3]> {
+    let load = System::load_average();
+    Some([load.one, load.five, load.fifteen])
+}
+
+#[cfg(not(unix))]
+fn read_load_average() -> Option<[f64; 3]> {
+    None
+}
+
+/// Heat map colour for a usage percentage: green at 0%, through yellow at 50%, to red at 100%.
+fn heat_color(usage: f32) -> Color {
+    let t = (usage / 100.0).clamp(0.0, 1.0);
+    if t < 0.5 {
+        Color::from_rgb(0.2 + 1.4 * t, 0.7, 0.2)
+    } else {
+        Color::from_rgb(0.9, 1.2 - t, 0.2)
+    }
+}
+
+/// One easing step from `current` toward `target`, landing exactly on `target` once
+/// within `epsilon` so displayed values never drift from the real reading.
+fn ease_toward(current: f64, target: f64, epsilon: f64) -> f64 {
+    let next = current + (target - current) * CARD_EASING;
+    if (target - next).abs() < epsilon {
+        target
+    } else {
+        next
+    }
+}
+
+fn default_debugger_command() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "lldb -p {pid}"
+    } else if cfg!(windows) {
+        "cmd /C start windbg -p {pid}"
+    } else {
+        "x-terminal-emulator -e gdb -p {pid}"
+    }
+}
+
+/// Splits the debugger command template on whitespace and substitutes `{pid}`.
+/// `None` when the template is blank.
+fn debugger_argv(template: &str, pid: Pid) -> Option<Vec<String>> {
+    let argv: Vec<String> = template
+        .split_whitespace()
+        .map(|arg| arg.replace("{pid}", &pid.to_string()))
+        .collect();
+    (!argv.is_empty()).then_some(argv)
+}
+
+/// Newest `snapshot_<timestamp>.json` file name written by "Save Snapshot".
+fn newest_snapshot(names: &[String]) -> Option<&String> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let stamp = name.strip_prefix("snapshot_")?.strip_suffix(".json")?;
+            let time = chrono::NaiveDateTime::parse_from_str(stamp, AUTO_EXPORT_TIMESTAMP_FORMAT).ok()?;
+            Some((time, name))
+        })
+        .max_by_key(|(time, _)| *time)
+        .map(|(_, name)| name)
+}
+
+/// Picks scheduled export files (by name) that exceed the retention limits: anything older
+/// than `max_age`, plus the oldest files beyond the newest `max_files`. Names that don't
+/// follow the `processes_<timestamp>.<csv|json>` scheme are never selected.
+fn exports_to_prune(
+    names: &[String],
+    max_files: usize,
+    max_age: chrono::Duration,
+    now: chrono::NaiveDateTime,
+) -> Vec<String> {
+    let mut exports: Vec<(chrono::NaiveDateTime, &String)> = names
+        .iter()
+        .filter_map(|name| {
+            let stem = name.strip_prefix("processes_")?;
+            let stamp = stem.strip_suffix(".csv").or_else(|| stem.strip_suffix(".json"))?;
+            let time = chrono::NaiveDateTime::parse_from_str(stamp, AUTO_EXPORT_TIMESTAMP_FORMAT).ok()?;
+            Some((time, name))
+        })
+        .collect();
+    exports.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+    exports
+        .into_iter()
+        .enumerate()
+        .filter(|(index, (time, _))| *index >= max_files || now - *time > max_age)
+        .map(|(_, (_, name))| name.clone())
+        .collect()
+}
+
+/// Flattens processes into parent/child display order. Processes whose parent isn't in
+/// `processes` become roots, and a PID cycle is broken at whichever member comes first, so
+/// every process appears exactly once. Children of rows at `depth_limit` are folded away
+/// unless the row is in `expanded`.
+fn flatten_process_tree<'a>(
+    processes: &[&'a ProcessData],
+    depth_limit: usize,
+    expanded: &std::collections::HashSet<Pid>,
+) -> Vec<TreeRow<'a>> {
+    let present: std::collections::HashSet<Pid> = processes.iter().map(|p| p.pid).collect();
+    let mut children: HashMap<Pid, Vec<&'a ProcessData>> = HashMap::new();
+    let mut roots = Vec::new();
+    for &process in processes {
+        match process.parent.filter(|ppid| *ppid != process.pid && present.contains(ppid)) {
+            Some(ppid) => children.entry(ppid).or_default().push(process),
+            None => roots.push(process),
         }
+    }
 
-        tokio::task::spawn_blocking(move || -> Result<(), String> {
-            let mut wtr = csv::Writer::from_path(path_buf)
-                .map_err(|e| e.to_string())?;
-            
-            for process in process_list {
-                wtr.serialize(process)
-                    .map_err(|e| e.to_string())?;
+    let mut visited = std::collections::HashSet::new();
+    let mut rows = Vec::new();
+    for &start in roots.iter().chain(processes) {
+        let mut stack = vec![(start, 0)];
+        while let Some((process, depth)) = stack.pop() {
+            if !visited.insert(process.pid) {
+                continue;
             }
-            
-            wtr.flush().map_err(|e| e.to_string())?;
-            Ok(())
-        })
-        .await
-        .map_err(|e| e.to_string())??;
-        Ok(path_str)
+            let kids = children.get(&process.pid).map_or(&[][..], Vec::as_slice);
+            let open = depth + 1 < depth_limit || expanded.contains(&process.pid);
+            let hidden_descendants = if open { 0 } else { fold_descendants(kids, &children, &mut visited) };
+            rows.push(TreeRow { process, depth, has_children: !kids.is_empty(), hidden_descendants });
+            if open {
+                stack.extend(kids.iter().rev().map(|&kid| (kid, depth + 1)));
+            }
+        }
     }
+    rows
+}
 
-    fn view_dashboard(&self) -> Element<'_, Message, Theme, Renderer> {
-        let header = row![
-            text("System Monitor").size(32),
-            Space::with_width(Length::Fill),
-            text("🟢 Real-time").style(Color::from_rgb(0.3, 0.9, 0.3)),
-        ]
-        .spacing(20)
-        .align_items(Alignment::Center);
+/// Marks every not-yet-visited descendant as visited and returns how many there were.
+fn fold_descendants(
+    kids: &[&ProcessData],
+    children: &HashMap<Pid, Vec<&ProcessData>>,
+    visited: &mut std::collections::HashSet<Pid>,
+) -> usize {
+    let mut stack: Vec<&ProcessData> = kids.to_vec();
+    let mut count = 0;
+    while let Some(process) = stack.pop() {
+        if visited.insert(process.pid) {
+            count += 1;
+            stack.extend(children.get(&process.pid).into_iter().flatten());
+        }
+    }
+    count
+}
 
-        let cpu_value = format!("{:.1}%", self.dashboard_data.cpu_usage);
-        let memory_value = format!("{:.1} / {:.1} GB", self.dashboard_data.memory_used, self.dashboard_data.memory_total);
-        let process_value = format!("{} running", self.dashboard_data.process_count);
+/// The `top` live processes with the most resident memory, largest first.
+fn top_by_memory(processes: &[ProcessData], top: usize) -> Vec<(Pid, String, u64)> {
+    let mut live: Vec<&ProcessData> = processes.iter().filter(|p| p.exited_at.is_none()).collect();
+    live.sort_by_key(|p| (std::cmp::Reverse(p.memory), p.pid));
+    live.into_iter().take(top).map(|p| (p.pid, p.name.clone(), p.memory)).collect()
+}
 
-        let data_cards = row![
-            create_card("CPU Usage", cpu_value),
-            create_card("Memory", memory_value),
-            create_card("Processes", process_value),
-        ]
-        .spacing(20);
+/// Sums CPU per owning user for live processes, highest first. Users past the first
+/// `top` are folded into a trailing "others" entry; unresolved owners count as "unknown".
+fn aggregate_cpu_by_user(processes: &[ProcessData], top: usize) -> Vec<(String, f32)> {
+    let mut totals: HashMap<&str, f32> = HashMap::new();
+    for process in processes.iter().filter(|p| p.exited_at.is_none()) {
+        *totals.entry(process.user.as_deref().unwrap_or("unknown")).or_insert(0.0) += process.cpu_usage;
+    }
+    let mut ranked: Vec<(String, f32)> = totals.into_iter().map(|(user, cpu)| (user.to_string(), cpu)).collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(&b.0)));
+    if ranked.len() > top {
+        let others: f32 = ranked.drain(top..).map(|(_, cpu)| cpu).sum();
+        ranked.push(("others".to_string(), others));
+    }
+    ranked
+}
 
-        column![
-            header,
-            Space::with_height(20),
-            text("System Overview").size(24),
-            Space::with_height(10),
-            data_cards,
-        ]
-        .align_items(Alignment::Center)
-        .into()
+/// Colour for a process status, with brighter shades on dark backgrounds.
+/// `None` means the theme's regular text colour (e.g. sleeping/idle processes).
+fn status_color(status: ProcessStatus, dark_theme: bool) -> Option<Color> {
+    let (dark, light) = match status {
+        ProcessStatus::Run => (Color::from_rgb(0.4, 0.9, 0.4), Color::from_rgb(0.1, 0.55, 0.1)),
+        ProcessStatus::Stop | ProcessStatus::Tracing => {
+            (Color::from_rgb(1.0, 0.75, 0.3), Color::from_rgb(0.7, 0.45, 0.0))
+        }
+        ProcessStatus::Zombie | ProcessStatus::Dead => {
+            (Color::from_rgb(1.0, 0.4, 0.4), Color::from_rgb(0.75, 0.1, 0.1))
+        }
+        _ => return None,
+    };
+    Some(if dark_theme { dark } else { light })
+}
+
+/// Appends to a history ring buffer, dropping the oldest sample beyond `HISTORY_LEN`.
+fn push_sample(history: &mut VecDeque<f32>, value: f32) {
+    if history.len() == HISTORY_LEN {
+        history.pop_front();
     }
+    history.push_back(value);
+}
 
-    fn view_processes(&self) -> Element<'_, Message, Theme, Renderer> {
-        let process_rows: Element<'_, Message, Theme, Renderer> = self.process_list.iter()
-            .fold(column![
-                row![
-                    text("PID").width(Length::Fixed(100.0)),
-                    text("Name").width(Length::Fill),
-                    text("CPU %").width(Length::Fixed(100.0)),
-                    text("Memory").width(Length::Fixed(100.0)),
-                ].spacing(10).padding(5),
-                Container::<Message, Theme, Renderer>::new(Space::with_height(2.0))
-                    .style(iced::theme::Container::Box)
-                    .width(Length::Fill)
-            ].spacing(5), 
-            |col, process| {
-                let pid = process.pid;
-                let mem_mb = process.memory as f64 / (1024.0 * 1024.0);
-                let process_row = row![
-                    text(pid.to_string()).width(Length::Fixed(100.0)),
-                    text(process.name.clone()).width(Length::Fill),
-                    text(format!("{:.1}", process.cpu_usage)).width(Length::Fixed(100.0)),
-                    text(format!("{:.1} MB", mem_mb)).width(Length::Fixed(100.0)),
-                ]
-                .spacing(10)
-                .align_items(Alignment::Center)
-                .padding(5);
-                
-                col.push(
-                    Button::new(process_row)
-                        .on_press(Message::ProcessSelected(pid))
-                        .style(if self.selected_process == Some(pid) {
-                            iced::theme::Button::Primary
-                        } else {
-                            iced::theme::Button::Text
-                        })
-                )
-            })
-            .into();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trend {
+    Rising,
+    Falling,
+    Flat,
+}
 
-        let process_table = Scrollable::new(process_rows)
-            .width(Length::FillPortion(2))
-            .height(Length::Fixed(600.0));
+/// Direction of the least-squares slope over the newest `window` samples.
+/// Slopes smaller than `threshold` (units per sample) count as flat so noise doesn't flicker.
+fn trend(history: &VecDeque<f32>, window: usize, threshold: f32) -> Trend {
+    let samples: Vec<f32> = history.iter().rev().take(window).rev().copied().collect();
+    if samples.len() < 3 {
+        return Trend::Flat;
+    }
+    let n = samples.len() as f32;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = samples.iter().sum::<f32>() / n;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (i, y) in samples.iter().enumerate() {
+        let dx = i as f32 - mean_x;
+        num += dx * (y - mean_y);
+        den += dx * dx;
+    }
+    let slope = num / den;
+    if slope > threshold {
+        Trend::Rising
+    } else if slope < -threshold {
+        Trend::Falling
+    } else {
+        Trend::Flat
+    }
+}
 
-        let detail_pane: Element<'_, Message, Theme, Renderer> = if let Some(pid) = self.selected_process {
-            if let Some(process) = self.system.process(pid) {
-                let mem_mb = process.memory() as f64 / (1024.0 * 1024.0);
-                column![
-                    text(format!("Details for: {}", process.name())).size(24),
-                    Space::with_height(10),
-                    text(format!("PID: {}", process.pid())),
-                    text(format!("CPU: {:.1} %", process.cpu_usage())),
-                    text(format!("Memory: {:.1} MB", mem_mb)),
-                    text(format!("Status: {:?}", process.status())),
-                    text(format!("Executable: {}", process.exe().map_or("N/A", |p| p.to_str().unwrap_or("N/A")))),
-                    text(format!("Command: {}", process.cmd().join(" "))),
-                    Space::with_height(Length::Fill),
-                    Button::new(text("Kill Process").style(Color::WHITE))
-                        .on_press(Message::KillProcessRequested(pid))
-                        .style(iced::theme::Button::Destructive)
-                        .padding(10)
-                ]
-                .spacing(10)
-                .padding(20)
-                .width(Length::Fill)
-                .into()
-            } else {
-                Container::<Message, Theme, Renderer>::new(text("Process disappeared."))
-                    .width(Length::Fill)
-                    .align_x(alignment::Horizontal::Center)
-                    .center_y()
-                    .into()
-            }
-        } else {
-            Container::<Message, Theme, Renderer>::new(text("Select a process from the list"))
-                .width(Length::Fill)
-                .align_x(alignment::Horizontal::Center)
-                .center_y()
-                .into()
-        };
+/// Formats `value` with `decimals` fractional digits, grouping the integer part per `grouping`.
+fn format_grouped(value: f64, decimals: usize, grouping: NumberGrouping) -> String {
+    let format = grouping.format();
+    let fixed = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = fixed.split_once('.').unwrap_or((fixed.as_str(), ""));
+    let int_value: u64 = int_part.parse().unwrap_or(0);
+    let mut out = String::new();
+    if value < 0.0 && fixed.chars().any(|c| c != '0' && c != '.') {
+        out.push('-');
+    }
+    out.push_str(&int_value.to_formatted_string(&format));
+    if !frac_part.is_empty() {
+        out.push_str(format.decimal());
+        out.push_str(frac_part);
+    }
+    out
+}
+
+/// Cumulative (read, written) disk bytes for every process `sys` knows about.
+fn disk_io_totals(sys: &System) -> HashMap<Pid, (u64, u64)> {
+    sys.processes()
+        .iter()
+        .map(|(&pid, p)| {
+            let usage = p.disk_usage();
+            (pid, (usage.total_read_bytes, usage.total_written_bytes))
+        })
+        .collect()
+}
+
+/// Bytes per second between two cumulative readings. A PID seen for the first time has no
+/// previous reading and reports 0 rather than its whole lifetime total as one spike; a total
+/// that went backwards (PID reused) also reports 0.
+fn disk_rate(previous: Option<u64>, total: u64, elapsed_secs: f64) -> f64 {
+    match previous {
+        Some(previous) if elapsed_secs > 0.0 => total.saturating_sub(previous) as f64 / elapsed_secs,
+        _ => 0.0,
+    }
+}
+
+/// Formats a duration in seconds as e.g. "2d 4h 13m", "1h 5m", "3m 12s" or "45s".
+fn format_duration(total_secs: u64) -> String {
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3_600;
+    let minutes = (total_secs % 3_600) / 60;
+    let seconds = total_secs % 60;
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// `pixels` in the wide table layout, one share of the row in the narrow one.
+fn extra_column_width(pixels: f32, narrow: bool) -> Length {
+    if narrow { Length::FillPortion(1) } else { Length::Fixed(pixels) }
+}
+
+/// Elapsed run time for the detail pane and table, "Unknown" when the start time is missing.
+fn run_time_label(run_time: Option<u64>) -> String {
+    run_time.map_or_else(|| "Unknown".to_string(), format_duration)
+}
+
+/// Local date and time a process started, "Unknown" for a zero or out-of-range timestamp.
+fn start_time_label(start_time: u64) -> String {
+    i64::try_from(start_time)
+        .ok()
+        .filter(|&secs| secs > 0)
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map_or_else(
+            || "Unknown".to_string(),
+            |utc| utc.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string(),
+        )
+}
+
+/// Highest `cpuinfo_max_freq` across cores from sysfs, in MHz.
+#[cfg(target_os = "linux")]
+fn read_max_cpu_frequency() -> Option<u64> {
+    let cpus = std::fs::read_dir("/sys/devices/system/cpu").ok()?;
+    cpus.filter_map(|entry| {
+        let path = entry.ok()?.path().join("cpufreq/cpuinfo_max_freq");
+        let khz: u64 = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+        Some(khz / 1000)
+    })
+    .max()
+}
 
-        let detail_container = Container::<Message, Theme, Renderer>::new(detail_pane)
-            .width(Length::FillPortion(1))
-            .height(Length::Fixed(600.0))
-            .style(iced::theme::Container::Box);
+#[cfg(not(target_os = "linux"))]
+fn read_max_cpu_frequency() -> Option<u64> {
+    None
+}
 
-        let content_row = row![
-            process_table,
-            detail_container,
-        ]
-        .spacing(20)
-        .width(Length::Fixed(1200.0));
-        column![
-            content_row,
-            Space::with_height(15),
-            Button::new(text("Export Process List to CSV"))
-                .on_press(Message::ExportCsvRequested)
-                .padding(10)
-        ]
-        .align_items(Alignment::Center)
-        .into()
-    }
+/// Reads the swapped-out size of a process from `/proc/<pid>/status`.
+///
+/// Best-effort: returns `None` when the file is unreadable (permissions, process gone)
+/// or the kernel does not report `VmSwap` (e.g. kernel threads).
+#[cfg(target_os = "linux")]
+fn read_process_swap(pid: Pid) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    parse_vm_swap(&status)
+}
 
-    fn view_settings(&self) -> Element<'_, Message, Theme, Renderer> {
-        let light_radio = Radio::new(
-            "Light Theme",
-            ThemeChoice::Light,
-            Some(self.settings.theme),
-            Message::ThemeChanged,
-        );
-        
-        let dark_radio = Radio::new(
-            "Dark Theme",
-            ThemeChoice::Dark,
-            Some(self.settings.theme),
-            Message::ThemeChanged,
-        );
+#[cfg(not(target_os = "linux"))]
+fn read_process_swap(_pid: Pid) -> Option<u64> {
+    None
+}
 
-        Container::<Message, Theme, Renderer>::new(
-            column![
-                text("Application Settings").size(24),
-                Space::with_height(20),
-                light_radio,
-                dark_radio,
-            ]
+/// Extracts the `VmSwap` line from a `/proc/<pid>/status` dump, in bytes.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_vm_swap(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmSwap:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmSwap:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+fn create_card(sizing: Sizing, title: &str, value: String) -> Element<'static, Message, Theme, Renderer> {
+    create_trend_card(sizing, title, value, Space::with_width(0.0).into())
+}
+
+/// A dashboard card whose value is followed by `indicator` (e.g. a trend arrow).
+fn create_trend_card<'a>(
+    sizing: Sizing,
+    title: &str,
+    value: String,
+    indicator: Element<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer> {
+    create_chart_card(sizing, title, value, indicator, Space::with_height(0.0).into())
+}
+
+/// A trend card with `chart` (e.g. a sparkline) under the value.
+fn create_chart_card<'a>(
+    sizing: Sizing,
+    title: &str,
+    value: String,
+    indicator: Element<'a, Message, Theme, Renderer>,
+    chart: Element<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer> {
+    let content = column![
+        text(title).size(sizing.card_title_size),
+        Space::with_height(sizing.card_gap),
+        row![text(value).size(sizing.card_value_size), indicator]
             .spacing(10)
-            .padding(20)
-        )
-        .width(Length::Fixed(1200.0))
-        .height(Length::Fixed(600.0))
-        .align_x(alignment::Horizontal::Left)
-        .style(iced::theme::Container::Box)
-        .into()
-    }
+            .align_items(Alignment::Center),
+        chart,
+    ]
+    .spacing(5)
+    .padding(sizing.card_padding)
+    .align_items(Alignment::Center);
+
+    card_container(content)
+}
+
+/// A dashboard card whose value is drawn in `color` when there is one.
+fn create_tinted_card(sizing: Sizing, title: &str, value: String, color: Option<Color>) -> Element<'static, Message, Theme, Renderer> {
+    let value = match color {
+        Some(color) => text(value).style(color),
+        None => text(value),
+    };
+    let content = column![
+        text(title).size(sizing.card_title_size),
+        Space::with_height(sizing.card_gap),
+        value.size(sizing.card_value_size),
+    ]
+    .spacing(5)
+    .padding(sizing.card_padding)
+    .align_items(Alignment::Center);
+
+    card_container(content)
 }
 
-fn create_card(title: &str, value: String) -> Element<'static, Message, Theme, Renderer> {
+/// A dashboard card with smaller `details` lines under the value.
+fn create_detail_card<'a>(
+    sizing: Sizing,
+    title: &str,
+    value: String,
+    details: Element<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer> {
     let content = column![
-        text(title).size(18),
-        Space::with_height(10),
-        text(value).size(36),
+        text(title).size(sizing.card_title_size),
+        Space::with_height(sizing.card_gap),
+        text(value).size(sizing.card_value_size),
+        details,
     ]
     .spacing(5)
-    .padding(20)
+    .padding(sizing.card_padding)
     .align_items(Alignment::Center);
 
-    Container::<'static, Message, Theme, Renderer>::new(content)
+    card_container(content)
+}
+
+fn card_container<'a>(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Element<'a, Message, Theme, Renderer> {
+    Container::<'a, Message, Theme, Renderer>::new(content)
         .style(|theme: &Theme| {
             let palette = theme.extended_palette();
             container::Appearance {
@@ -703,6 +8084,156 @@ fn create_card(title: &str, value: String) -> Element<'static, Message, Theme, R
         .into()
 }
 
+/// The last `SPARKLINE_SAMPLES` percentages of `data` as a small line chart. Until
+/// `SPARKLINE_MIN_SAMPLES` have arrived it is blank space of the same height, so the card
+/// doesn't change size when the line appears.
+fn sparkline<'a>(data: &VecDeque<f32>, sizing: Sizing, color: Color) -> Element<'a, Message, Theme, Renderer> {
+    if data.len() < SPARKLINE_MIN_SAMPLES {
+        return Space::with_height(sizing.sparkline_height).into();
+    }
+    let recent: VecDeque<f32> = data.iter().skip(data.len().saturating_sub(SPARKLINE_SAMPLES)).copied().collect();
+    Canvas::new(LineChart::new(&recent, SPARKLINE_SAMPLES, 100.0, color))
+        .width(Length::Fill)
+        .height(Length::Fixed(sizing.sparkline_height))
+        .into()
+}
+
+/// A simple line chart over a fixed-length sample window, scaled from 0 to `max_value`.
+struct LineChart {
+    samples: Vec<f32>,
+    capacity: usize,
+    max_value: f32,
+    color: Color,
+}
+
+impl LineChart {
+    fn new(samples: &VecDeque<f32>, capacity: usize, max_value: f32, color: Color) -> Self {
+        Self {
+            samples: samples.iter().copied().collect(),
+            capacity: capacity.max(samples.len()).max(2),
+            max_value: max_value.max(f32::EPSILON),
+            color,
+        }
+    }
+}
+
+impl<Message> canvas::Program<Message> for LineChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let palette = theme.extended_palette();
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), palette.background.weak.color);
+
+        if self.samples.len() >= 2 {
+            let step = bounds.width / (self.capacity - 1) as f32;
+            // Newest sample sits on the right edge so the chart scrolls leftwards.
+            let offset = (self.capacity - self.samples.len()) as f32 * step;
+            let line = Path::new(|builder| {
+                for (i, value) in self.samples.iter().enumerate() {
+                    let ratio = (value / self.max_value).clamp(0.0, 1.0);
+                    let point = Point::new(offset + i as f32 * step, bounds.height * (1.0 - ratio));
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+            frame.stroke(&line, Stroke::default().with_color(self.color).with_width(2.0));
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Dims `base` and shows `dialog` in a bordered box, as used by confirmation prompts.
+fn modal_overlay<'a>(
+    base: Element<'a, Message, Theme, Renderer>,
+    dialog: Element<'a, Message, Theme, Renderer>,
+) -> Element<'a, Message, Theme, Renderer> {
+    Container::<Message, Theme, Renderer>::new(
+        column![
+            Container::<Message, Theme, Renderer>::new(base)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(|_theme: &Theme| container::Appearance {
+                    background: Some(iced::Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.3))),
+                    ..Default::default()
+                }),
+            Container::<Message, Theme, Renderer>::new(
+                Container::<Message, Theme, Renderer>::new(dialog)
+                    .padding(30)
+                    .center_x()
+            )
+            .style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+                container::Appearance {
+                    background: Some(iced::Background::Color(palette.background.base.color)),
+                    border: Border {
+                        color: palette.background.strong.color,
+                        width: 2.0,
+                        radius: 10.0.into(),
+                    },
+                    ..Default::default()
+                }
+            })
+            .width(Length::Fixed(500.0))
+            .center_x(),
+        ]
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .center_x()
+    .center_y()
+    .into()
+}
+
+fn process_table_id() -> scrollable::Id {
+    scrollable::Id::new("process-table")
+}
+
+fn event_log_id() -> scrollable::Id {
+    scrollable::Id::new("event-log")
+}
+
+/// A label followed by a value the user can select and copy with the keyboard.
+///
+/// iced has no selectable text widget, so this is a text input whose edits are discarded:
+/// selection, Ctrl+C and Ctrl+A work, but the shown value always stays `value`.
+fn selectable_field(label: &str, value: String) -> Element<'static, Message, Theme, Renderer> {
+    row![
+        text(label),
+        text_input("", &value)
+            .on_input(|_| Message::SelectableTextEdited)
+            .padding(2)
+            .width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_items(Alignment::Center)
+    .into()
+}
+
+fn create_badge(label: &str, color: Color) -> Element<'static, Message, Theme, Renderer> {
+    Container::<'static, Message, Theme, Renderer>::new(
+        text(label).size(16).style(Color::WHITE)
+    )
+    .padding([5, 10])
+    .style(move |_theme: &Theme| container::Appearance {
+        background: Some(iced::Background::Color(color)),
+        border: Border { radius: 5.0.into(), ..Default::default() },
+        ..Default::default()
+    })
+    .into()
+}
+
 fn create_tab_button(label: &str, tab: Tab, active_tab: Tab) -> Element<'static, Message, Theme, Renderer> {
     let is_active = tab == active_tab;
     Button::new(
@@ -723,8 +8254,21 @@ fn create_tab_button(label: &str, tab: Tab, active_tab: Tab) -> Element<'static,
 
 #[cfg(test)]
 mod tests {
-    use super::System;
+    use super::{
+        aggregate_cpu_by_user, backed_off_interval, core_bar_columns, detail_row, diagnostics_report,
+        disk_rate, distinct_users, exports_to_prune, flatten_process_tree, format_duration, format_grouped, format_memory,
+        heat_color, heat_grid_columns, load_color, meets_usage_minimums, name_matches_filter, newest_snapshot, nice_after,
+        parse_minimum,
+        process_matches_filter,
+        parse_vm_swap, process_lifecycle_events, selection_is_current, sort_rows, start_time_label, status_from_label, trend, App, AppSettings,
+        Language, MemoryMetric, MemoryUnit, MetricsSnapshot, NumberGrouping, OsInfo, PendingSave, Pid, ProcessData, ProcessStatus,
+        RebuildTiming, SettingsProfile, SortColumn, System, SystemData, ThemeChoice, TreeRow, Trend, UsageAlert,
+        ALERT_COOLDOWN, ALERT_SUSTAIN_SAMPLES, MAX_PROCESS_REFRESH_INTERVAL,
+    };
+    use std::collections::{HashSet, VecDeque};
+    use std::time::{Duration, Instant};
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_sysinfo_data_retrieval() {
         let mut sys = System::new_all();
         assert!(sys.total_memory() > 0, "Unable to read full Memory info");
@@ -737,4 +8281,553 @@ mod tests {
         let process_count = sys.processes().len();
         assert!(process_count > 0, "Need a running process");
     }
+
+    #[test]
+    fn test_build_process_list_reuses_rows() {
+        let mut sys = System::new();
+        sys.refresh_processes();
+        let users = sysinfo::Users::new();
+        let (first, gone) = App::build_process_list(Vec::new(), &sys, &users, None);
+        assert!(gone.is_empty());
+        let mut previous = first.clone();
+        previous[0].swap = Some(4096);
+        previous[1].start_time += 1;
+        let (second, gone) = App::build_process_list(previous, &sys, &users, None);
+        assert_eq!(second.len(), first.len());
+        let row = |pid| second.iter().find(|p| p.pid == pid).unwrap();
+        assert_eq!(row(first[0].pid).swap, Some(4096), "the same process keeps its row");
+        assert!(gone.is_empty());
+        assert_eq!(row(first[1].pid).start_time, first[1].start_time, "a recycled PID gets a fresh row");
+    }
+
+    #[test]
+    fn test_parse_vm_swap() {
+        let status = "Name:\tbash\nVmRSS:\t    5120 kB\nVmSwap:\t     256 kB\nThreads:\t1\n";
+        assert_eq!(parse_vm_swap(status), Some(256 * 1024));
+        assert_eq!(parse_vm_swap("Name:\tkthreadd\nThreads:\t1\n"), None);
+    }
+
+    #[test]
+    fn test_format_grouped() {
+        assert_eq!(format_grouped(1_234_567.85, 1, NumberGrouping::Comma), "1,234,567.9");
+        assert_eq!(format_grouped(1_234_567.85, 1, NumberGrouping::Period), "1.234.567,9");
+        assert_eq!(format_grouped(1_234_567.0, 0, NumberGrouping::Space), "1 234 567");
+        assert_eq!(format_grouped(1_234_567.25, 2, NumberGrouping::None), "1234567.25");
+        assert_eq!(format_grouped(-1_500.0, 0, NumberGrouping::Comma), "-1,500");
+    }
+
+    #[test]
+    fn test_trend() {
+        let rising: VecDeque<f32> = (0..10).map(|i| i as f32 * 2.0).collect();
+        let falling: VecDeque<f32> = rising.iter().rev().copied().collect();
+        let noisy: VecDeque<f32> = [50.0, 50.3, 49.8, 50.1, 50.0, 49.9].into_iter().collect();
+        assert_eq!(trend(&rising, 10, 0.5), Trend::Rising);
+        assert_eq!(trend(&falling, 10, 0.5), Trend::Falling);
+        assert_eq!(trend(&noisy, 10, 0.5), Trend::Flat);
+        assert_eq!(trend(&VecDeque::new(), 10, 0.5), Trend::Flat);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_describe_os_error() {
+        use super::describe_os_error;
+        let eperm = std::io::Error::from_raw_os_error(libc::EPERM);
+        let esrch = std::io::Error::from_raw_os_error(libc::ESRCH);
+        assert_eq!(describe_os_error(&eperm), "permission denied");
+        assert_eq!(describe_os_error(&esrch), "no such process");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(192), "3m 12s");
+        assert_eq!(format_duration(3_900), "1h 5m");
+        assert_eq!(format_duration(187_980), "2d 4h 13m");
+    }
+
+    #[test]
+    fn test_start_time_label() {
+        assert_eq!(start_time_label(0), "Unknown");
+        assert_eq!(start_time_label(u64::MAX), "Unknown");
+        let expected = chrono::DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        assert_eq!(start_time_label(1_700_000_000), expected);
+    }
+
+    #[test]
+    fn test_exports_to_prune() {
+        let names: Vec<String> = [
+            "processes_20240110_120000.csv",
+            "processes_20240109_120000.json",
+            "processes_20240108_120000.csv",
+            "processes_20231201_120000.csv",
+            "processes_export.csv",
+            "notes.txt",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+        let now = chrono::NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(13, 0, 0).unwrap();
+        assert_eq!(
+            exports_to_prune(&names, 2, chrono::Duration::days(30), now),
+            vec!["processes_20240108_120000.csv".to_string(), "processes_20231201_120000.csv".to_string()]
+        );
+        assert_eq!(
+            exports_to_prune(&names, 10, chrono::Duration::days(30), now),
+            vec!["processes_20231201_120000.csv".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_newest_snapshot() {
+        let names: Vec<String> = [
+            "snapshot_20240108_120000.json",
+            "snapshot_20240110_090000.json",
+            "snapshot_20240109_235959.json",
+            "snapshot_latest.json",
+            "processes_20240111_120000.json",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+        assert_eq!(newest_snapshot(&names).map(String::as_str), Some("snapshot_20240110_090000.json"));
+        assert_eq!(newest_snapshot(&names[3..]), None);
+    }
+
+    fn process_row(pid: usize, cpu_usage: f32) -> ProcessData {
+        ProcessData {
+            pid: Pid::from(pid),
+            name: format!("p{}", pid),
+            cpu_usage,
+            memory: 0,
+            swap: None,
+            status: ProcessStatus::Run,
+            exe: None,
+            cmd: String::new(),
+            user: None,
+            parent: None,
+            exited_at: None,
+            disk_read_rate: 0.0,
+            disk_write_rate: 0.0,
+            run_time: None,
+            start_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_status_from_label() {
+        assert_eq!(status_from_label(&ProcessStatus::Run.to_string()), ProcessStatus::Run);
+        assert_eq!(status_from_label("not a status"), ProcessStatus::Unknown(0));
+    }
+
+    #[test]
+    fn test_theme_resolve() {
+        assert_eq!(ThemeChoice::System.resolve(true), ThemeChoice::Dark);
+        assert_eq!(ThemeChoice::System.resolve(false), ThemeChoice::Light);
+        assert_eq!(ThemeChoice::Light.resolve(true), ThemeChoice::Light);
+        assert_eq!(ThemeChoice::Dark.resolve(false), ThemeChoice::Dark);
+    }
+
+    #[test]
+    fn test_settings_from_json() {
+        let settings = AppSettings { process_row_limit: 250, theme: ThemeChoice::Light, ..AppSettings::default() };
+        let pasted = format!("\n  {}\n", settings.to_json().unwrap());
+        let parsed = AppSettings::from_json(&pasted).unwrap();
+        assert_eq!(parsed.process_row_limit, 250);
+        assert_eq!(parsed.theme, ThemeChoice::Light);
+
+        let partial = AppSettings::from_json(r#"{ "tooltip_delay_ms": 100 }"#).unwrap();
+        assert_eq!(partial.tooltip_delay_ms, 100);
+        assert_eq!(partial.process_row_limit, AppSettings::default().process_row_limit, "missing fields use defaults");
+
+        assert!(AppSettings::from_json("not settings").is_err());
+        assert!(AppSettings::from_json(r#"{ "theme": "Purple" }"#).is_err());
+        assert!(AppSettings::from_json(r#"{ "process_row_limit": 0 }"#).is_err());
+        assert!(AppSettings::from_json(r#"{ "visible_columns": [] }"#).is_err());
+        assert!(AppSettings::from_json(r#"{ "tooltip_delay_ms": 20000 }"#).is_err());
+        assert!(AppSettings::from_json(r#"{ "cpu_alert_threshold": 150.0 }"#).is_err());
+        assert!(AppSettings::from_json(r#"{ "mem_alert_threshold": 5.0 }"#).is_err(), "below the slider's range");
+    }
+
+    #[test]
+    fn test_settings_profile_round_trip() {
+        let mut current = AppSettings { process_row_limit: 500, read_only: true, ..AppSettings::default() };
+        let profile = SettingsProfile::capture("mine", &current).unwrap();
+        assert!(!profile.settings.contains_key("read_only"), "safety switches are not captured");
+        assert!(!profile.settings.contains_key("profiles"));
+
+        current.process_row_limit = 100;
+        current.read_only = false;
+        let applied = profile.apply(&current).unwrap();
+        assert_eq!(applied.process_row_limit, 500);
+        assert!(!applied.read_only, "applying a profile leaves read-only mode alone");
+
+        let invalid = SettingsProfile {
+            name: "broken".to_string(),
+            settings: serde_json::json!({ "process_row_limit": 0 }).as_object().unwrap().clone(),
+        };
+        assert!(invalid.apply(&current).is_err());
+    }
+
+    #[test]
+    fn test_corrupt_settings_are_backed_up_and_reset() {
+        let dir = std::env::temp_dir().join(format!("system_monitor_settings_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("settings.json");
+        std::fs::write(&path, "{ \"theme\": Dark, ").unwrap();
+
+        let (settings, backup) = AppSettings::load_from(&path).unwrap();
+        let defaults = AppSettings::default();
+        assert_eq!(settings.process_row_limit, defaults.process_row_limit);
+        assert_eq!(settings.tooltip_delay_ms, defaults.tooltip_delay_ms);
+        assert_eq!(backup.as_deref(), Some(dir.join("settings.json.bak").as_path()));
+        assert_eq!(std::fs::read_to_string(dir.join("settings.json.bak")).unwrap(), "{ \"theme\": Dark, ");
+        assert!(AppSettings::from_json(&std::fs::read_to_string(&path).unwrap()).is_ok());
+
+        // The rewritten file loads cleanly, so the next launch doesn't report it again.
+        let (_, backup) = AppSettings::load_from(&path).unwrap();
+        assert!(backup.is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_pending_save_debounces() {
+        let mut pending = PendingSave::default();
+        assert!(!pending.flush(0), "nothing to write yet");
+        let first = pending.mark();
+        let second = pending.mark();
+        assert!(!pending.flush(first), "superseded by a newer change");
+        assert!(pending.flush(second));
+        assert!(!pending.flush(second), "already written");
+    }
+
+    #[test]
+    fn test_pending_save_taken_on_quit() {
+        let mut pending = PendingSave::default();
+        assert!(!pending.take());
+        let generation = pending.mark();
+        assert!(pending.take(), "quit writes a change still waiting out its debounce");
+        assert!(!pending.flush(generation), "and its delayed flush doesn't write it again");
+    }
+
+    #[test]
+    fn test_usage_alert_debounces() {
+        let start = Instant::now();
+        let mut alert = UsageAlert::default();
+        let fired: Vec<bool> = (0..8)
+            .map(|i| alert.observe(95.0, 90.0, start + Duration::from_secs(i)))
+            .collect();
+        assert_eq!(fired.iter().filter(|&&f| f).count(), 1, "fires once while usage stays high");
+        assert!(fired[ALERT_SUSTAIN_SAMPLES as usize - 1]);
+
+        // Dropping below re-arms the alert, but the cooldown still applies.
+        assert!(!alert.observe(50.0, 90.0, start + Duration::from_secs(10)));
+        let soon = (0..ALERT_SUSTAIN_SAMPLES).any(|i| alert.observe(95.0, 90.0, start + Duration::from_secs(11 + i as u64)));
+        assert!(!soon);
+        alert.observe(50.0, 90.0, start + ALERT_COOLDOWN);
+        let later = (0..ALERT_SUSTAIN_SAMPLES).any(|i| alert.observe(95.0, 90.0, start + ALERT_COOLDOWN + Duration::from_secs(1 + i as u64)));
+        assert!(later);
+    }
+
+    #[test]
+    fn test_format_memory() {
+        let gib = 1024 * 1024 * 1024;
+        assert_eq!(format_memory(3 * gib / 2, MemoryUnit::GiB, NumberGrouping::Comma), "1.50 GiB");
+        assert_eq!(format_memory(3 * gib / 2, MemoryUnit::GB, NumberGrouping::Comma), "1.61 GB");
+        assert_eq!(format_memory(5 * 1024 * 1024, MemoryUnit::MiB, NumberGrouping::Period), "5,0 MiB");
+        assert_eq!(format_memory(1_234_567, MemoryUnit::Bytes, NumberGrouping::Comma), "1,234,567 B");
+        assert_eq!(format_memory(0, MemoryUnit::Auto, NumberGrouping::Comma), "0 B");
+        assert_eq!(format_memory(512, MemoryUnit::Auto, NumberGrouping::Comma), "512 B");
+        assert_eq!(format_memory(1536, MemoryUnit::Auto, NumberGrouping::Comma), "1.5 KiB");
+        assert_eq!(format_memory(3 * gib, MemoryUnit::Auto, NumberGrouping::Space), "3,0 GiB");
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let mut a = process_row(3, 10.0);
+        a.memory = 500;
+        let mut b = process_row(1, 50.0);
+        b.memory = 100;
+        let c = process_row(2, 10.0);
+        let pids = |rows: &[&ProcessData]| rows.iter().map(|p| p.pid.as_u32()).collect::<Vec<_>>();
+
+        let mut rows = vec![&a, &b, &c];
+        sort_rows(&mut rows, SortColumn::Cpu, false);
+        assert_eq!(pids(&rows), [1, 2, 3], "ties on CPU fall back to PID order");
+        sort_rows(&mut rows, SortColumn::Memory, false);
+        assert_eq!(pids(&rows), [3, 1, 2]);
+        sort_rows(&mut rows, SortColumn::Pid, true);
+        assert_eq!(pids(&rows), [1, 2, 3]);
+        sort_rows(&mut rows, SortColumn::Name, false);
+        assert_eq!(pids(&rows), [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_name_matches_filter() {
+        assert!(name_matches_filter("Firefox", ""));
+        assert!(name_matches_filter("Firefox", "  "));
+        assert!(name_matches_filter("Firefox", "FIRE"));
+        assert!(name_matches_filter("firefox-bin", "fox"));
+        assert!(!name_matches_filter("Firefox", "chrome"));
+
+        let script = ProcessData {
+            name: "python3".to_string(),
+            cmd: "python3 /opt/jobs/backup.py".to_string(),
+            ..process_row(1, 0.0)
+        };
+        assert!(!process_matches_filter(&script, "backup", false));
+        assert!(process_matches_filter(&script, "BACKUP", true));
+        assert!(process_matches_filter(&script, "python", false));
+    }
+
+    #[test]
+    fn test_core_bar_columns() {
+        assert_eq!(core_bar_columns(0), 1);
+        assert_eq!(core_bar_columns(4), 4);
+        assert_eq!(core_bar_columns(12), 6);
+        assert_eq!(core_bar_columns(32), 8);
+        assert_eq!(core_bar_columns(33), 7);
+    }
+
+    #[test]
+    fn test_backed_off_interval() {
+        let interval = Duration::from_secs(2);
+        assert_eq!(backed_off_interval(interval, Duration::from_millis(200)), None);
+        assert_eq!(backed_off_interval(interval, Duration::from_millis(1500)), Some(Duration::from_secs(4)));
+        assert_eq!(
+            backed_off_interval(Duration::from_secs(12), Duration::from_secs(10)),
+            Some(MAX_PROCESS_REFRESH_INTERVAL)
+        );
+        assert_eq!(backed_off_interval(MAX_PROCESS_REFRESH_INTERVAL, Duration::from_secs(60)), None);
+    }
+
+    #[test]
+    fn test_metrics_to_prometheus() {
+        let snapshot = MetricsSnapshot {
+            cpu_usage_percent: 12.5,
+            memory_used_bytes: 2048,
+            memory_total_bytes: 4096,
+            swap_used_bytes: 0,
+            swap_total_bytes: 0,
+            process_count: 42,
+        };
+        let exposition = snapshot.to_prometheus();
+        assert!(exposition.contains("# TYPE system_cpu_usage_percent gauge\nsystem_cpu_usage_percent 12.5\n"));
+        assert!(exposition.contains("\nsystem_memory_used_bytes 2048\n"));
+        assert!(exposition.contains("\nsystem_process_count 42\n"));
+        assert_eq!(exposition.lines().filter(|line| line.starts_with("# HELP ")).count(), 6);
+        assert!(exposition.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_diagnostics_report() {
+        let os = OsInfo {
+            name: Some("Linux".to_string()),
+            os_version: None,
+            kernel_version: None,
+            host_name: None,
+            cpu_brand: None,
+            core_count: 4,
+            physical_core_count: None,
+        };
+        let data = SystemData {
+            cpu_usage: 12.5,
+            memory_used: 3 << 30,
+            memory_metric: MemoryMetric::Used,
+            memory_other: None,
+            memory_total: 8 << 30,
+            swap_used: 0,
+            swap_total: 0,
+            per_core_usage: vec![10.0, 15.0],
+            per_core_frequency: vec![0, 0],
+            max_frequency: None,
+            uptime_secs: 3_600,
+            boot_time: 1_700_000_000,
+            load_average: None,
+            process_count: 2,
+        };
+        let settings = AppSettings {
+            auto_export_dir: "/home/alice/exports".to_string(),
+            ..AppSettings::default()
+        };
+        let processes = [process_row(1, 2.0), process_row(42, 30.0)];
+        let timing = RebuildTiming { last: Some(Duration::from_millis(35)), ..RebuildTiming::default() };
+        let report = diagnostics_report(&os, &data, &processes, &timing, &settings);
+
+        assert!(report.contains("OS: Linux unknown"));
+        assert!(report.contains("CPU: unknown (4 cores)"));
+        assert!(report.contains("CPU usage: 12.5%"));
+        assert!(report.contains("Process rebuild: last 35 ms"));
+        assert!(report.find("p42").unwrap() < report.find("p1 ").unwrap(), "busiest process listed first");
+        assert!(!report.contains("/home/alice"), "paths are redacted");
+        assert!(report.contains("<redacted>"));
+    }
+
+    #[test]
+    fn test_detail_row_survives_refresh() {
+        let grace = Duration::from_secs(2);
+        let previous = vec![process_row(1, 5.0), process_row(2, 50.0)];
+
+        // PID 1 jumps to the top of the CPU sort: the pane keeps following it with fresh values.
+        let mut current = vec![process_row(1, 90.0), process_row(2, 1.0)];
+        App::retain_exited_rows(previous.clone(), &mut current, grace);
+        let row = detail_row(&current, Pid::from(1)).expect("selected PID should still be listed");
+        assert_eq!(row.cpu_usage, 90.0);
+        assert!(row.exited_at.is_none());
+
+        // PID 1 missing from one refresh is kept as exited during the grace period...
+        let mut current = vec![process_row(2, 1.0)];
+        App::retain_exited_rows(previous.clone(), &mut current, grace);
+        assert!(detail_row(&current, Pid::from(1)).is_some_and(|row| row.exited_at.is_some()));
+
+        // ...and only reported gone without one.
+        let mut current = vec![process_row(2, 1.0)];
+        App::retain_exited_rows(previous, &mut current, Duration::ZERO);
+        assert!(detail_row(&current, Pid::from(1)).is_none());
+    }
+
+    #[test]
+    fn test_load_color() {
+        assert_eq!(load_color(1.0, 4), None);
+        assert!(load_color(3.0, 4).is_some_and(|c| c.g > 0.5), "amber near the core count");
+        assert!(load_color(4.5, 4).is_some_and(|c| c.g < 0.5), "red above the core count");
+    }
+
+    #[test]
+    fn test_disk_rate() {
+        assert_eq!(disk_rate(None, 10_000_000, 2.0), 0.0, "first sample is not a spike");
+        assert_eq!(disk_rate(Some(1_000), 5_000, 2.0), 2_000.0);
+        assert_eq!(disk_rate(Some(5_000), 1_000, 2.0), 0.0, "reused PID starts a new total");
+        assert_eq!(disk_rate(Some(1_000), 5_000, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("th_TH.UTF-8"), Language::Thai);
+        assert_eq!(Language::from_locale("en_US.UTF-8"), Language::English);
+        assert_eq!(Language::from_locale("C"), Language::English);
+    }
+
+    #[test]
+    fn test_process_lifecycle_events() {
+        let previous: HashSet<Pid> = [Pid::from(1), Pid::from(2)].into_iter().collect();
+        let old = vec![process_row(1, 0.0), process_row(2, 0.0)];
+        let current = vec![process_row(1, 0.0), process_row(3, 0.0)];
+        assert_eq!(
+            process_lifecycle_events(&previous, &old, &current, Language::English),
+            vec!["Started: p3 (3)".to_string(), "Exited: p2 (2)".to_string()]
+        );
+        // A row kept for the grace period is not reported as exiting twice.
+        let live: HashSet<Pid> = current.iter().map(|p| p.pid).collect();
+        assert!(process_lifecycle_events(&live, &old, &current, Language::English).is_empty());
+    }
+
+    #[test]
+    fn test_usage_minimums() {
+        let row = ProcessData { memory: 512 * 1024 * 1024, ..process_row(1, 25.0) };
+        assert!(meets_usage_minimums(&row, 0.0, 0.0));
+        assert!(meets_usage_minimums(&row, 25.0, 512.0));
+        assert!(!meets_usage_minimums(&row, 30.0, 0.0));
+        assert!(!meets_usage_minimums(&row, 10.0, 1024.0), "both minimums must be met");
+        assert_eq!(parse_minimum(" 12.5 "), 12.5);
+        assert_eq!(parse_minimum(""), 0.0);
+        assert_eq!(parse_minimum("lots"), 0.0);
+    }
+
+    #[test]
+    fn test_heat_grid() {
+        assert_eq!(heat_grid_columns(0), 1);
+        assert_eq!(heat_grid_columns(4), 2);
+        assert_eq!(heat_grid_columns(64), 8);
+        assert_eq!(heat_grid_columns(1024), 16);
+        let (idle, busy) = (heat_color(0.0), heat_color(100.0));
+        assert!(idle.g > idle.r, "idle cores are green");
+        assert!(busy.r > busy.g, "busy cores are red");
+        assert_eq!(heat_color(150.0), busy);
+    }
+
+    #[test]
+    fn test_nice_after() {
+        assert_eq!(nice_after(0, 5), 5);
+        assert_eq!(nice_after(17, 5), 19);
+        assert_eq!(nice_after(-18, -5), -20);
+    }
+
+    #[test]
+    fn test_selection_cleared_on_pid_reuse() {
+        let pid = Pid::from(1);
+        assert!(selection_is_current(&[process_row(1, 5.0)], pid, Some("p1")));
+
+        // The selected process exited and the OS handed its PID to another program.
+        let reused = [ProcessData { name: "other".to_string(), ..process_row(1, 5.0) }];
+        assert!(!selection_is_current(&reused, pid, Some("p1")));
+        assert!(!selection_is_current(&[], pid, Some("p1")));
+        assert!(selection_is_current(&reused, pid, None), "without a recorded name only the PID is checked");
+    }
+
+    #[test]
+    fn test_flatten_process_tree() {
+        let process = |pid: usize, parent: Option<usize>| ProcessData {
+            parent: parent.map(Pid::from),
+            ..process_row(pid, 0.0)
+        };
+        // 1 -> 2 -> 3 -> 4, 5 is orphaned (parent 99 is gone), 6 and 7 are each other's parent.
+        let processes = [
+            process(1, None),
+            process(2, Some(1)),
+            process(3, Some(2)),
+            process(4, Some(3)),
+            process(5, Some(99)),
+            process(6, Some(7)),
+            process(7, Some(6)),
+        ];
+        let refs: Vec<&ProcessData> = processes.iter().collect();
+        let shape = |rows: Vec<TreeRow<'_>>| -> Vec<(usize, usize, usize)> {
+            rows.iter().map(|r| (r.process.pid.as_u32() as usize, r.depth, r.hidden_descendants)).collect()
+        };
+
+        let mut expanded = HashSet::new();
+        assert_eq!(
+            shape(flatten_process_tree(&refs, 2, &expanded)),
+            vec![(1, 0, 0), (2, 1, 2), (5, 0, 0), (6, 0, 0), (7, 1, 0)]
+        );
+        expanded.insert(Pid::from(2));
+        assert_eq!(
+            shape(flatten_process_tree(&refs, 2, &expanded)),
+            vec![(1, 0, 0), (2, 1, 0), (3, 2, 1), (5, 0, 0), (6, 0, 0), (7, 1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_cpu_by_user() {
+        let process = |user: Option<&str>, cpu_usage: f32| ProcessData {
+            user: user.map(str::to_string),
+            ..process_row(1, cpu_usage)
+        };
+        let processes = [
+            process(Some("alice"), 10.0),
+            process(Some("bob"), 30.0),
+            process(Some("alice"), 25.0),
+            process(None, 5.0),
+            process(Some("carol"), 2.0),
+        ];
+        assert_eq!(
+            aggregate_cpu_by_user(&processes, 2),
+            vec![("alice".to_string(), 35.0), ("bob".to_string(), 30.0), ("others".to_string(), 7.0)]
+        );
+        assert_eq!(aggregate_cpu_by_user(&processes, 5)[2], ("unknown".to_string(), 5.0));
+    }
+
+    #[test]
+    fn test_distinct_users() {
+        let process = |pid: usize, user: Option<&str>| ProcessData {
+            user: user.map(str::to_string),
+            ..process_row(pid, 0.0)
+        };
+        let processes = [process(1, Some("bob")), process(2, None), process(3, Some("alice")), process(4, Some("bob"))];
+        assert_eq!(distinct_users(&processes), vec!["alice", "bob"]);
+    }
 }